@@ -0,0 +1,5 @@
+#[dyn_adapter]
+trait Visitor<'a, #[dynamic] T, U> {
+    fn visit(&mut self, node: &T, extra: &'a U) -> Option<U>;
+    fn leave(&mut self, node: Box<T>) -> Box<T>;
+}
@@ -0,0 +1,5 @@
+#[dyn_trait(constructor_only)]
+pub trait Plugin {
+    fn name() -> String;
+    fn version() -> (u32, u32);
+}
@@ -0,0 +1,5 @@
+#[dyn_trait(instance_only)]
+pub trait Meta: Clone {
+    fn get(&self) -> i32;
+    fn merge(self, other: Self) -> Self;
+}
@@ -0,0 +1,6 @@
+#[dyn_trait(instance = DynShape, constructor = ShapeFactory, vis = pub(crate), alias = BoxShape)]
+pub trait Shape<T>: Clone {
+    fn area(&self) -> T;
+    fn scale(self, factor: T) -> Self;
+    fn unit() -> Self;
+}
@@ -0,0 +1,9 @@
+#[dyn_trait(on_mismatch = "error")]
+pub trait Meta {
+    fn merge(&mut self, other: &Self) -> Result<(), Error>;
+    fn pair(&self, other: Box<Self>) -> Option<(Self, Self)>;
+    fn eq(&self, other: &Self) -> bool;
+    fn check(&self, other: &Self) -> std::result::Result<(), Error>;
+    fn fmt(&self, other: &Self) -> fmt::Result;
+    fn read(&self, other: &Self) -> io::Result<usize>;
+}
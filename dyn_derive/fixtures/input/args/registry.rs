@@ -0,0 +1,5 @@
+#[dyn_trait(registry)]
+pub trait Shape {
+    fn area(&self) -> f64;
+    fn unit() -> Self;
+}
@@ -0,0 +1,5 @@
+#[dyn_trait(Send, Sync)]
+pub trait Meta: Clone + PartialEq + Add {
+    fn get(&self) -> i32;
+    fn new(value: i32) -> Self;
+}
@@ -0,0 +1,6 @@
+#[dyn_impl]
+impl Shape for Circle {
+    fn unit() -> Self {
+        Self(1.0)
+    }
+}
@@ -0,0 +1,9 @@
+#[dyn_impl(name = "circle")]
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        3.14 * self.0 * self.0
+    }
+    fn unit() -> Self {
+        Self(1.0)
+    }
+}
@@ -0,0 +1,7 @@
+#[derive(DynMap)]
+enum Either<L, R> {
+    Left(L),
+    Right(R),
+    Both { left: Box<L>, right: Option<R> },
+    Neither,
+}
@@ -0,0 +1,5 @@
+#[derive(DynMap)]
+enum Tree<E> {
+    Leaf(E),
+    Node(Vec<Tree<E>>, Option<(E, usize)>),
+}
@@ -0,0 +1,8 @@
+#[derive(DynMap)]
+struct Spanned<'a, T, const N: usize> {
+    value: T,
+    name: &'a str,
+    pairs: ([T; N], Vec<(usize, String)>),
+    children: Box<Spanned<'a, T, N>>,
+    ids: HashMap<UUID, T>,
+}
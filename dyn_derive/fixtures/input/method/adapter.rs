@@ -0,0 +1,6 @@
+#[dyn_trait]
+trait Meta {
+    fn method_1(&self, arg: &mut dyn Visitor<Self>);
+    fn method_2(arg: &dyn Observer<Self, i32>);
+    fn method_3(arg: Box<dyn Observer<Self, i32>>);
+}
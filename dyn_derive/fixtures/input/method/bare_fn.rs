@@ -0,0 +1,8 @@
+#[dyn_trait]
+trait Meta<T> {
+    fn method_1(arg: fn(T) -> T);
+    fn method_2(&mut self, arg: fn(&Self, &Self) -> bool);
+    fn method_3(arg: fn(Self) -> Self);
+    fn method_4(arg: (T, fn(Self))) -> fn() -> Self;
+    fn method_5(arg: &dyn Fn(fn(Self) -> Self));
+}
@@ -0,0 +1,8 @@
+#[dyn_trait]
+trait Meta {
+    fn deque(arg: VecDeque<Self>) -> LinkedList<Self>;
+    fn boxed(arg: Box<[Self]>) -> Box<[(Self, i32)]>;
+    fn cell(arg: RefCell<Self>) -> Mutex<Self>;
+    fn hasher(arg: FxHashMap<i32, Self>);
+    fn explicit_hasher(arg: HashMap<i32, Self, FxBuildHasher>) -> std::collections::HashSet<Self, FxBuildHasher>;
+}
@@ -0,0 +1,7 @@
+#[dyn_trait]
+trait Meta {
+    fn array_vec(arg: ArrayVec<Self, { CAP }>) -> ArrayVec<Self, 4>;
+    fn small_vec(arg: SmallVec<[Self; SIZE]>) -> Option<[Self; 2]>;
+    fn hash_map(arg: HashMap<UUID, Self>);
+    fn wide(arg: Wide<i8, i16, i32, i64, u8, u16, u32, u64, Self>);
+}
@@ -0,0 +1,6 @@
+#[dyn_trait]
+trait Meta {
+    fn spawn(&self) -> Pin<Box<dyn Future<Output = Self> + Send>>;
+    fn join(&self, other: Pin<&mut dyn Future<Output = Option<Self>>>);
+    fn collect(items: Pin<Box<dyn Stream<Item = Self>>>) -> Pin<Box<dyn Future<Output = Self>>>;
+}
@@ -0,0 +1,7 @@
+#[dyn_trait]
+trait Meta {
+    fn from_iter(items: Box<dyn Iterator<Item = Self>>) -> Self;
+    fn extend(&mut self, items: &mut dyn DoubleEndedIterator<Item = (Self, i32)>);
+    fn iter(&self) -> Box<dyn ExactSizeIterator<Item = Self> + '_>;
+    fn options(&self) -> Box<dyn Iterator<Item = Option<Self>>>;
+}
@@ -0,0 +1,7 @@
+#[dyn_trait]
+trait Meta<T> {
+    fn method_1(arg: *const T);
+    fn method_2(&mut self, peer: *mut Self);
+    fn method_3(arg: (*const Self, T)) -> *mut Self;
+    fn method_4(arg: &dyn Fn(*mut Self));
+}
@@ -0,0 +1,3 @@
+#[derive(DynName)]
+#[dyn_name = "geo.circle/v1"]
+pub struct Circle(f64);
@@ -0,0 +1,2 @@
+#[dyn_trait]
+pub trait Meta: AddAssign {}
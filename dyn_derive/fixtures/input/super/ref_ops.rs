@@ -0,0 +1,5 @@
+#[dyn_trait]
+pub trait Meta: for<'a> SubAssign<&'a Self>
+where
+    for<'a> &'a Self: Add<&'a Self, Output = Self>,
+{}
@@ -0,0 +1,4 @@
+#[dyn_trait(registry, tag = "type")]
+pub trait Shape: serde::Serialize + serde::de::DeserializeOwned {
+    fn area(&self) -> f64;
+}
@@ -0,0 +1,4 @@
+#[dyn_trait]
+pub trait Shape: self::Serialize {
+    fn area(&self) -> f64;
+}
@@ -0,0 +1,23 @@
+trait Visitor<'a, T: ?Sized, U> {
+    fn visit(&mut self, node: &T, extra: &'a U) -> Option<U>;
+    fn leave(&mut self, node: Box<T>) -> Box<T>;
+}
+#[automatically_derived]
+impl<'inner, 'a, T, U, DynT: ?Sized, Inner> Visitor<'a, T, U>
+for ::dyn_std::adapt::Adapter<Inner>
+where
+    T: 'static,
+    DynT: ::dyn_std::adapt::Wrap<T>,
+    Inner: ::core::ops::DerefMut<Target = dyn Visitor<'a, DynT, U> + 'inner>,
+{
+    #[inline]
+    fn visit(&mut self, a1: &T, a2: &'a U) -> Option<U> {
+        let a1 = <DynT as ::dyn_std::adapt::Wrap<T>>::wrap_ref(a1);
+        self.0.visit(a1, a2)
+    }
+    #[inline]
+    fn leave(&mut self, a1: Box<T>) -> Box<T> {
+        let a1 = <DynT as ::dyn_std::adapt::Wrap<T>>::wrap(*a1);
+        Box::new(<DynT as ::dyn_std::adapt::Wrap<T>>::unwrap(self.0.leave(a1)))
+    }
+}
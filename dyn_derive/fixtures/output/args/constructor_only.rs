@@ -0,0 +1,96 @@
+pub trait Plugin: Sized + 'static {
+    fn name() -> String;
+    fn version() -> (u32, u32);
+}
+pub trait PluginConstructor {
+    fn name(&self) -> String;
+    fn version(&self) -> (u32, u32);
+    #[doc(hidden)]
+    fn dyn_type_name_of_inner(&self) -> &'static str
+    where
+        Self: 'static,
+    {
+        ::std::any::type_name::<Self>()
+    }
+    #[doc(hidden)]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId
+    where
+        Self: 'static,
+    {
+        ::std::any::TypeId::of::<Self>()
+    }
+}
+#[automatically_derived]
+impl<Factory: Plugin> PluginConstructor for ::dyn_std::Constructor<Factory> {
+    #[inline]
+    fn name(&self) -> String {
+        Factory::name()
+    }
+    #[inline]
+    fn version(&self) -> (u32, u32) {
+        Factory::version()
+    }
+    #[inline]
+    fn dyn_type_name_of_inner(&self) -> &'static str {
+        ::std::any::type_name::<Factory>()
+    }
+    #[inline]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Factory>()
+    }
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for dyn PluginConstructor {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(f, "Constructor<{}>", self.dyn_type_name_of_inner())
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for dyn PluginConstructor {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_type_id_of_inner() == other.dyn_type_id_of_inner()
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for dyn PluginConstructor {}
+#[automatically_derived]
+impl ::core::hash::Hash for dyn PluginConstructor {
+    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+        self.dyn_type_id_of_inner().hash(state)
+    }
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for dyn PluginConstructor + Send + Sync {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(f, "Constructor<{}>", self.dyn_type_name_of_inner())
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for dyn PluginConstructor + Send + Sync {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_type_id_of_inner() == other.dyn_type_id_of_inner()
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for dyn PluginConstructor + Send + Sync {}
+#[automatically_derived]
+impl ::core::hash::Hash for dyn PluginConstructor + Send + Sync {
+    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+        self.dyn_type_id_of_inner().hash(state)
+    }
+}
+#[automatically_derived]
+impl<Factory: Plugin> ::dyn_std::registry::Construct<Factory> for dyn PluginConstructor {
+    #[inline]
+    fn construct() -> Box<Self> {
+        Box::new(::dyn_std::Constructor::<Factory>::new())
+    }
+}
+#[automatically_derived]
+impl<Factory: Plugin> ::dyn_std::registry::Construct<Factory>
+for dyn PluginConstructor + Send + Sync {
+    #[inline]
+    fn construct() -> Box<Self> {
+        Box::new(::dyn_std::Constructor::<Factory>::new())
+    }
+}
@@ -0,0 +1,91 @@
+pub trait Meta: Clone + Sized + 'static {
+    fn get(&self) -> i32;
+    fn merge(self, other: Self) -> Self;
+}
+pub trait MetaInstance: ::dyn_std::clone::Clone + ::dyn_std::any::Dyn {
+    fn get(&self) -> i32;
+    fn merge(self: Box<Self>, other: Box<dyn MetaInstance>) -> Box<dyn MetaInstance>;
+    #[doc(hidden)]
+    fn dyn_type_name_of_inner(&self) -> &'static str {
+        ::dyn_std::Dyn::type_name(self)
+    }
+    #[doc(hidden)]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId {
+        ::std::any::Any::type_id(self)
+    }
+}
+#[automatically_derived]
+impl Clone for Box<dyn MetaInstance> {
+    #[inline]
+    fn clone(&self) -> Self {
+        ::dyn_std::Fat::to_box(self, ::dyn_std::clone::Clone::dyn_clone)
+    }
+}
+#[automatically_derived]
+impl<Factory: Meta> MetaInstance for ::dyn_std::Instance<Factory> {
+    #[inline]
+    fn get(&self) -> i32 {
+        self.0.get()
+    }
+    #[inline]
+    fn merge(self: Box<Self>, a1: Box<dyn MetaInstance>) -> Box<dyn MetaInstance> {
+        let a1 = ::dyn_std::Instance::<Factory>::downcast(a1);
+        Box::new(::dyn_std::Instance::new(self.0.merge(a1)))
+    }
+    #[inline]
+    fn dyn_type_name_of_inner(&self) -> &'static str {
+        ::std::any::type_name::<Factory>()
+    }
+    #[inline]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Factory>()
+    }
+}
+#[automatically_derived]
+impl ::dyn_std::Downcast for dyn MetaInstance {
+    #[inline]
+    fn type_name_of_inner(&self) -> &'static str {
+        self.dyn_type_name_of_inner()
+    }
+    #[inline]
+    fn type_id_of_inner(&self) -> ::std::any::TypeId {
+        self.dyn_type_id_of_inner()
+    }
+}
+#[automatically_derived]
+impl ::dyn_std::Downcast for dyn MetaInstance + Send {
+    #[inline]
+    fn type_name_of_inner(&self) -> &'static str {
+        self.dyn_type_name_of_inner()
+    }
+    #[inline]
+    fn type_id_of_inner(&self) -> ::std::any::TypeId {
+        self.dyn_type_id_of_inner()
+    }
+}
+#[automatically_derived]
+impl ::dyn_std::Downcast for dyn MetaInstance + Send + Sync {
+    #[inline]
+    fn type_name_of_inner(&self) -> &'static str {
+        self.dyn_type_name_of_inner()
+    }
+    #[inline]
+    fn type_id_of_inner(&self) -> ::std::any::TypeId {
+        self.dyn_type_id_of_inner()
+    }
+}
+#[automatically_derived]
+impl<Factory: Meta> ::dyn_std::adapt::Wrap<Factory> for dyn MetaInstance {
+    #[inline]
+    fn wrap(value: Factory) -> Box<Self> {
+        Box::new(::dyn_std::Instance::new(value))
+    }
+    #[inline]
+    fn wrap_ref(value: &Factory) -> &Self {
+        ::dyn_std::Instance::from_ref(value)
+    }
+    #[inline]
+    fn wrap_mut(value: &mut Factory) -> &mut Self {
+        ::dyn_std::Instance::from_mut(value)
+    }
+}
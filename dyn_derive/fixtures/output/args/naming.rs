@@ -0,0 +1,273 @@
+pub trait Shape<T: 'static>: Clone + Sized + 'static {
+    fn area(&self) -> T;
+    fn scale(self, factor: T) -> Self;
+    fn unit() -> Self;
+}
+pub(crate) trait DynShape<T>: ::dyn_std::clone::Clone + ::dyn_std::any::Dyn {
+    fn area(&self) -> T;
+    fn scale(self: Box<Self>, factor: T) -> Box<dyn DynShape<T>>;
+    #[doc(hidden)]
+    fn dyn_type_name_of_inner(&self) -> &'static str {
+        ::dyn_std::Dyn::type_name(self)
+    }
+    #[doc(hidden)]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId {
+        ::std::any::Any::type_id(self)
+    }
+    #[doc(hidden)]
+    fn dyn_constructor(&self) -> Option<&'static dyn ShapeFactory<T>> {
+        None
+    }
+}
+pub(crate) trait ShapeFactory<T> {
+    fn unit(&self) -> Box<dyn DynShape<T>>;
+    /// Wraps a value of the implementation into an instance, or gives it back if it is of another type.
+    fn dyn_wrap(
+        &self,
+        value: Box<dyn ::std::any::Any>,
+    ) -> Result<Box<dyn DynShape<T>>, Box<dyn ::std::any::Any>> {
+        Err(value)
+    }
+    /// Wraps a reference to a value of the implementation into an instance, or returns `None` if it is of another type.
+    fn dyn_wrap_ref<'value>(
+        &self,
+        _value: &'value dyn ::std::any::Any,
+    ) -> Option<&'value dyn DynShape<T>> {
+        None
+    }
+    #[doc(hidden)]
+    fn dyn_type_name_of_inner(&self) -> &'static str
+    where
+        Self: 'static,
+    {
+        ::std::any::type_name::<Self>()
+    }
+    #[doc(hidden)]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId
+    where
+        Self: 'static,
+    {
+        ::std::any::TypeId::of::<Self>()
+    }
+}
+pub(crate) type BoxShape<T> = Box<dyn DynShape<T>>;
+#[automatically_derived]
+impl<T> Clone for Box<dyn DynShape<T>> {
+    #[inline]
+    fn clone(&self) -> Self {
+        ::dyn_std::Fat::to_box(self, ::dyn_std::clone::Clone::dyn_clone)
+    }
+}
+#[automatically_derived]
+impl<T: 'static, Factory: Shape<T>> DynShape<T> for ::dyn_std::Instance<Factory> {
+    #[inline]
+    fn area(&self) -> T {
+        self.0.area()
+    }
+    #[inline]
+    fn scale(self: Box<Self>, a1: T) -> Box<dyn DynShape<T>> {
+        Box::new(::dyn_std::Instance::new(self.0.scale(a1)))
+    }
+    #[inline]
+    fn dyn_type_name_of_inner(&self) -> &'static str {
+        ::std::any::type_name::<Factory>()
+    }
+    #[inline]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Factory>()
+    }
+    #[inline]
+    fn dyn_constructor(&self) -> Option<&'static dyn ShapeFactory<T>> {
+        Some(const { &::dyn_std::Constructor::<Factory>::new() })
+    }
+}
+#[automatically_derived]
+impl<T: 'static, Factory: Shape<T>> ShapeFactory<T> for ::dyn_std::Constructor<Factory> {
+    #[inline]
+    fn unit(&self) -> Box<dyn DynShape<T>> {
+        Box::new(::dyn_std::Instance::new(Factory::unit()))
+    }
+    #[inline]
+    fn dyn_wrap(
+        &self,
+        value: Box<dyn ::std::any::Any>,
+    ) -> Result<Box<dyn DynShape<T>>, Box<dyn ::std::any::Any>> {
+        Ok(Box::new(::dyn_std::Instance::new(*value.downcast::<Factory>()?)))
+    }
+    #[inline]
+    fn dyn_wrap_ref<'value>(
+        &self,
+        value: &'value dyn ::std::any::Any,
+    ) -> Option<&'value dyn DynShape<T>> {
+        Some(::dyn_std::Instance::from_ref(value.downcast_ref::<Factory>()?))
+    }
+    #[inline]
+    fn dyn_type_name_of_inner(&self) -> &'static str {
+        ::std::any::type_name::<Factory>()
+    }
+    #[inline]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Factory>()
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::core::fmt::Debug for dyn ShapeFactory<T> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(f, "Constructor<{}>", self.dyn_type_name_of_inner())
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::core::cmp::PartialEq for dyn ShapeFactory<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_type_id_of_inner() == other.dyn_type_id_of_inner()
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::core::cmp::Eq for dyn ShapeFactory<T> {}
+#[automatically_derived]
+impl<T: 'static> ::core::hash::Hash for dyn ShapeFactory<T> {
+    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+        self.dyn_type_id_of_inner().hash(state)
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::core::fmt::Debug for dyn ShapeFactory<T> + Send + Sync {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(f, "Constructor<{}>", self.dyn_type_name_of_inner())
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::core::cmp::PartialEq for dyn ShapeFactory<T> + Send + Sync {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_type_id_of_inner() == other.dyn_type_id_of_inner()
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::core::cmp::Eq for dyn ShapeFactory<T> + Send + Sync {}
+#[automatically_derived]
+impl<T: 'static> ::core::hash::Hash for dyn ShapeFactory<T> + Send + Sync {
+    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+        self.dyn_type_id_of_inner().hash(state)
+    }
+}
+impl<T: 'static> dyn ShapeFactory<T> {
+    /// Wraps a value of the implementation into an instance, or gives it back if it is of another type.
+    #[inline]
+    pub fn wrap(
+        &self,
+        value: Box<dyn ::std::any::Any>,
+    ) -> Result<Box<dyn DynShape<T>>, Box<dyn ::std::any::Any>> {
+        self.dyn_wrap(value)
+    }
+    /// Wraps a reference to a value of the implementation into an instance, or returns `None` if it is of another type.
+    #[inline]
+    pub fn wrap_ref<'value>(
+        &self,
+        value: &'value dyn ::std::any::Any,
+    ) -> Option<&'value dyn DynShape<T>> {
+        self.dyn_wrap_ref(value)
+    }
+}
+impl<T: 'static> dyn ShapeFactory<T> + Send + Sync {
+    /// Wraps a value of the implementation into an instance, or gives it back if it is of another type.
+    #[inline]
+    pub fn wrap(
+        &self,
+        value: Box<dyn ::std::any::Any>,
+    ) -> Result<Box<dyn DynShape<T>>, Box<dyn ::std::any::Any>> {
+        self.dyn_wrap(value)
+    }
+    /// Wraps a reference to a value of the implementation into an instance, or returns `None` if it is of another type.
+    #[inline]
+    pub fn wrap_ref<'value>(
+        &self,
+        value: &'value dyn ::std::any::Any,
+    ) -> Option<&'value dyn DynShape<T>> {
+        self.dyn_wrap_ref(value)
+    }
+}
+#[automatically_derived]
+impl<T: 'static, Factory: Shape<T>> ::dyn_std::registry::Construct<Factory>
+for dyn ShapeFactory<T> {
+    #[inline]
+    fn construct() -> Box<Self> {
+        Box::new(::dyn_std::Constructor::<Factory>::new())
+    }
+}
+#[automatically_derived]
+impl<T: 'static, Factory: Shape<T>> ::dyn_std::registry::Construct<Factory>
+for dyn ShapeFactory<T> + Send + Sync {
+    #[inline]
+    fn construct() -> Box<Self> {
+        Box::new(::dyn_std::Constructor::<Factory>::new())
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::dyn_std::Downcast for dyn DynShape<T> {
+    #[inline]
+    fn type_name_of_inner(&self) -> &'static str {
+        self.dyn_type_name_of_inner()
+    }
+    #[inline]
+    fn type_id_of_inner(&self) -> ::std::any::TypeId {
+        self.dyn_type_id_of_inner()
+    }
+}
+impl<T: 'static> dyn DynShape<T> {
+    /// Returns the constructor of the implementation, or `None` if it is not an `Instance<T>`.
+    #[inline]
+    pub fn constructor(&self) -> Option<&'static dyn ShapeFactory<T>> {
+        self.dyn_constructor()
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::dyn_std::Downcast for dyn DynShape<T> + Send {
+    #[inline]
+    fn type_name_of_inner(&self) -> &'static str {
+        self.dyn_type_name_of_inner()
+    }
+    #[inline]
+    fn type_id_of_inner(&self) -> ::std::any::TypeId {
+        self.dyn_type_id_of_inner()
+    }
+}
+impl<T: 'static> dyn DynShape<T> + Send {
+    /// Returns the constructor of the implementation, or `None` if it is not an `Instance<T>`.
+    #[inline]
+    pub fn constructor(&self) -> Option<&'static dyn ShapeFactory<T>> {
+        self.dyn_constructor()
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::dyn_std::Downcast for dyn DynShape<T> + Send + Sync {
+    #[inline]
+    fn type_name_of_inner(&self) -> &'static str {
+        self.dyn_type_name_of_inner()
+    }
+    #[inline]
+    fn type_id_of_inner(&self) -> ::std::any::TypeId {
+        self.dyn_type_id_of_inner()
+    }
+}
+impl<T: 'static> dyn DynShape<T> + Send + Sync {
+    /// Returns the constructor of the implementation, or `None` if it is not an `Instance<T>`.
+    #[inline]
+    pub fn constructor(&self) -> Option<&'static dyn ShapeFactory<T>> {
+        self.dyn_constructor()
+    }
+}
+#[automatically_derived]
+impl<T: 'static, Factory: Shape<T>> ::dyn_std::adapt::Wrap<Factory> for dyn DynShape<T> {
+    #[inline]
+    fn wrap(value: Factory) -> Box<Self> {
+        Box::new(::dyn_std::Instance::new(value))
+    }
+    #[inline]
+    fn wrap_ref(value: &Factory) -> &Self {
+        ::dyn_std::Instance::from_ref(value)
+    }
+    #[inline]
+    fn wrap_mut(value: &mut Factory) -> &mut Self {
+        ::dyn_std::Instance::from_mut(value)
+    }
+}
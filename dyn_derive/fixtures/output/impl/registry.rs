@@ -0,0 +1,56 @@
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        3.14 * self.0 * self.0
+    }
+    fn unit() -> Self {
+        Self(1.0)
+    }
+}
+const _: () = {
+    static SUBMISSION: ::dyn_std::registry::Submission<
+        <ShapeRegistry as ::dyn_std::registry::Collect>::Value,
+    > = ::dyn_std::registry::Submission::new::<Circle>("circle");
+    #[cfg(
+        not(
+            any(
+                target_os = "linux",
+                target_os = "android",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd",
+                target_os = "dragonfly",
+                target_os = "illumos",
+                target_os = "macos",
+                target_os = "ios",
+                windows
+            )
+        )
+    )]
+    compile_error!(
+        "`dyn_impl` is not supported on this target, as it relies on platform constructors"
+    );
+    #[used]
+    #[cfg_attr(
+        any(
+            target_os = "linux",
+            target_os = "android",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "dragonfly",
+            target_os = "illumos"
+        ),
+        link_section = ".init_array"
+    )]
+    #[cfg_attr(
+        any(target_os = "macos", target_os = "ios"),
+        link_section = "__DATA,__mod_init_func"
+    )]
+    #[cfg_attr(windows, link_section = ".CRT$XCU")]
+    static SUBMIT: extern "C" fn() = {
+        extern "C" fn submit() {
+            SUBMISSION.submit();
+        }
+        submit
+    };
+};
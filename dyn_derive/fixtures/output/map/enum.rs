@@ -0,0 +1,40 @@
+#[automatically_derived]
+impl<L, R> ::dyn_std::map::Map2<L, R> for Either<L, R> {
+    type Input<S1, S2> = Either<S1, S2>;
+    #[inline]
+    fn map<S1, S2>(
+        value: Self::Input<S1, S2>,
+        f1: &mut impl FnMut(S1) -> L,
+        f2: &mut impl FnMut(S2) -> R,
+    ) -> Self {
+        match value {
+            Either::Left(a1) => Either::Left(f1(a1)),
+            Either::Right(a1) => Either::Right(f2(a1)),
+            Either::Both { left, right } => {
+                Either::Both {
+                    left: Box::new(f1(*left)),
+                    right: ::dyn_std::map::Map1::map(right, f2),
+                }
+            }
+            Either::Neither => Either::Neither,
+        }
+    }
+    #[inline]
+    fn try_map<S1, S2, E>(
+        value: Self::Input<S1, S2>,
+        f1: &mut impl FnMut(S1) -> Result<L, E>,
+        f2: &mut impl FnMut(S2) -> Result<R, E>,
+    ) -> Result<Self, E> {
+        match value {
+            Either::Left(a1) => Ok(Either::Left(f1(a1)?)),
+            Either::Right(a1) => Ok(Either::Right(f2(a1)?)),
+            Either::Both { left, right } => {
+                Ok(Either::Both {
+                    left: Box::new(f1(*left)?),
+                    right: ::dyn_std::map::Map1::try_map(right, f2)?,
+                })
+            }
+            Either::Neither => Ok(Either::Neither),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+#[automatically_derived]
+impl<E> ::dyn_std::map::Map1<E> for Tree<E> {
+    type Input<S1> = Tree<S1>;
+    #[inline]
+    fn map<S1>(value: Self::Input<S1>, f1: &mut impl FnMut(S1) -> E) -> Self {
+        match value {
+            Tree::Leaf(a1) => Tree::Leaf(f1(a1)),
+            Tree::Node(a1, a2) => {
+                Tree::Node(
+                    ::dyn_std::map::Map1::map(
+                        a1,
+                        &mut |x| ::dyn_std::map::Map1::map(x, f1),
+                    ),
+                    ::dyn_std::map::Map1::map(
+                        a2,
+                        &mut |x| {
+                            let (x1, x2) = x;
+                            (f1(x1), x2)
+                        },
+                    ),
+                )
+            }
+        }
+    }
+    #[inline]
+    fn try_map<S1, E1>(
+        value: Self::Input<S1>,
+        f1: &mut impl FnMut(S1) -> Result<E, E1>,
+    ) -> Result<Self, E1> {
+        match value {
+            Tree::Leaf(a1) => Ok(Tree::Leaf(f1(a1)?)),
+            Tree::Node(a1, a2) => {
+                Ok(
+                    Tree::Node(
+                        ::dyn_std::map::Map1::try_map(
+                            a1,
+                            &mut |x| ::dyn_std::map::Map1::try_map(x, f1),
+                        )?,
+                        ::dyn_std::map::Map1::try_map(
+                            a2,
+                            &mut |x| Ok::<
+                                _,
+                                E1,
+                            >({
+                                let (x1, x2) = x;
+                                (f1(x1)?, x2)
+                            }),
+                        )?,
+                    ),
+                )
+            }
+        }
+    }
+}
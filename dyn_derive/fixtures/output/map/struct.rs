@@ -0,0 +1,41 @@
+#[automatically_derived]
+impl<'a, T, const N: usize> ::dyn_std::map::Map1<T> for Spanned<'a, T, N> {
+    type Input<S1> = Spanned<'a, S1, N>;
+    #[inline]
+    fn map<S1>(value: Self::Input<S1>, f1: &mut impl FnMut(S1) -> T) -> Self {
+        match value {
+            Spanned { value, name, pairs, children, ids } => {
+                Spanned {
+                    value: f1(value),
+                    name,
+                    pairs: {
+                        let (x1, x2) = pairs;
+                        (::dyn_std::map::Map1::map(x1, f1), x2)
+                    },
+                    children: Box::new(::dyn_std::map::Map1::map(*children, f1)),
+                    ids: ::dyn_std::map::Map2::map(ids, &mut |x| x, f1),
+                }
+            }
+        }
+    }
+    #[inline]
+    fn try_map<S1, E>(
+        value: Self::Input<S1>,
+        f1: &mut impl FnMut(S1) -> Result<T, E>,
+    ) -> Result<Self, E> {
+        match value {
+            Spanned { value, name, pairs, children, ids } => {
+                Ok(Spanned {
+                    value: f1(value)?,
+                    name,
+                    pairs: {
+                        let (x1, x2) = pairs;
+                        (::dyn_std::map::Map1::try_map(x1, f1)?, x2)
+                    },
+                    children: Box::new(::dyn_std::map::Map1::try_map(*children, f1)?),
+                    ids: ::dyn_std::map::Map2::try_map(ids, &mut |x| Ok::<_, E>(x), f1)?,
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,267 @@
+trait Meta: Sized + 'static {
+    fn method_1(&self, arg: &mut dyn Visitor<Self>);
+    fn method_2(arg: &dyn Observer<Self, i32>);
+    fn method_3(arg: Box<dyn Observer<Self, i32>>);
+}
+trait MetaInstance: ::dyn_std::any::Dyn {
+    fn method_1(&self, arg: &mut dyn Visitor<dyn MetaInstance>);
+    #[doc(hidden)]
+    fn dyn_type_name_of_inner(&self) -> &'static str {
+        ::dyn_std::Dyn::type_name(self)
+    }
+    #[doc(hidden)]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId {
+        ::std::any::Any::type_id(self)
+    }
+    #[doc(hidden)]
+    fn dyn_constructor(&self) -> Option<&'static dyn MetaConstructor> {
+        None
+    }
+}
+trait MetaConstructor {
+    fn method_2(&self, arg: &dyn Observer<dyn MetaInstance, i32>);
+    fn method_3(&self, arg: Box<dyn Observer<dyn MetaInstance, i32>>);
+    /// Wraps a value of the implementation into an instance, or gives it back if it is of another type.
+    fn dyn_wrap(
+        &self,
+        value: Box<dyn ::std::any::Any>,
+    ) -> Result<Box<dyn MetaInstance>, Box<dyn ::std::any::Any>> {
+        Err(value)
+    }
+    /// Wraps a reference to a value of the implementation into an instance, or returns `None` if it is of another type.
+    fn dyn_wrap_ref<'value>(
+        &self,
+        _value: &'value dyn ::std::any::Any,
+    ) -> Option<&'value dyn MetaInstance> {
+        None
+    }
+    #[doc(hidden)]
+    fn dyn_type_name_of_inner(&self) -> &'static str
+    where
+        Self: 'static,
+    {
+        ::std::any::type_name::<Self>()
+    }
+    #[doc(hidden)]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId
+    where
+        Self: 'static,
+    {
+        ::std::any::TypeId::of::<Self>()
+    }
+}
+#[automatically_derived]
+impl<Factory: Meta> MetaInstance for ::dyn_std::Instance<Factory> {
+    #[inline]
+    fn method_1(&self, a1: &mut dyn Visitor<dyn MetaInstance>) {
+        let a1 = &mut ::dyn_std::adapt::Adapter(a1);
+        self.0.method_1(a1)
+    }
+    #[inline]
+    fn dyn_type_name_of_inner(&self) -> &'static str {
+        ::std::any::type_name::<Factory>()
+    }
+    #[inline]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Factory>()
+    }
+    #[inline]
+    fn dyn_constructor(&self) -> Option<&'static dyn MetaConstructor> {
+        Some(const { &::dyn_std::Constructor::<Factory>::new() })
+    }
+}
+#[automatically_derived]
+impl<Factory: Meta> MetaConstructor for ::dyn_std::Constructor<Factory> {
+    #[inline]
+    fn method_2(&self, a1: &dyn Observer<dyn MetaInstance, i32>) {
+        let a1 = &::dyn_std::adapt::Adapter(a1);
+        Factory::method_2(a1)
+    }
+    #[inline]
+    fn method_3(&self, a1: Box<dyn Observer<dyn MetaInstance, i32>>) {
+        let a1 = Box::new(::dyn_std::adapt::Adapter(a1));
+        Factory::method_3(a1)
+    }
+    #[inline]
+    fn dyn_wrap(
+        &self,
+        value: Box<dyn ::std::any::Any>,
+    ) -> Result<Box<dyn MetaInstance>, Box<dyn ::std::any::Any>> {
+        Ok(Box::new(::dyn_std::Instance::new(*value.downcast::<Factory>()?)))
+    }
+    #[inline]
+    fn dyn_wrap_ref<'value>(
+        &self,
+        value: &'value dyn ::std::any::Any,
+    ) -> Option<&'value dyn MetaInstance> {
+        Some(::dyn_std::Instance::from_ref(value.downcast_ref::<Factory>()?))
+    }
+    #[inline]
+    fn dyn_type_name_of_inner(&self) -> &'static str {
+        ::std::any::type_name::<Factory>()
+    }
+    #[inline]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Factory>()
+    }
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for dyn MetaConstructor {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(f, "Constructor<{}>", self.dyn_type_name_of_inner())
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for dyn MetaConstructor {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_type_id_of_inner() == other.dyn_type_id_of_inner()
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for dyn MetaConstructor {}
+#[automatically_derived]
+impl ::core::hash::Hash for dyn MetaConstructor {
+    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+        self.dyn_type_id_of_inner().hash(state)
+    }
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for dyn MetaConstructor + Send + Sync {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(f, "Constructor<{}>", self.dyn_type_name_of_inner())
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for dyn MetaConstructor + Send + Sync {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_type_id_of_inner() == other.dyn_type_id_of_inner()
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for dyn MetaConstructor + Send + Sync {}
+#[automatically_derived]
+impl ::core::hash::Hash for dyn MetaConstructor + Send + Sync {
+    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+        self.dyn_type_id_of_inner().hash(state)
+    }
+}
+impl dyn MetaConstructor {
+    /// Wraps a value of the implementation into an instance, or gives it back if it is of another type.
+    #[inline]
+    pub fn wrap(
+        &self,
+        value: Box<dyn ::std::any::Any>,
+    ) -> Result<Box<dyn MetaInstance>, Box<dyn ::std::any::Any>> {
+        self.dyn_wrap(value)
+    }
+    /// Wraps a reference to a value of the implementation into an instance, or returns `None` if it is of another type.
+    #[inline]
+    pub fn wrap_ref<'value>(
+        &self,
+        value: &'value dyn ::std::any::Any,
+    ) -> Option<&'value dyn MetaInstance> {
+        self.dyn_wrap_ref(value)
+    }
+}
+impl dyn MetaConstructor + Send + Sync {
+    /// Wraps a value of the implementation into an instance, or gives it back if it is of another type.
+    #[inline]
+    pub fn wrap(
+        &self,
+        value: Box<dyn ::std::any::Any>,
+    ) -> Result<Box<dyn MetaInstance>, Box<dyn ::std::any::Any>> {
+        self.dyn_wrap(value)
+    }
+    /// Wraps a reference to a value of the implementation into an instance, or returns `None` if it is of another type.
+    #[inline]
+    pub fn wrap_ref<'value>(
+        &self,
+        value: &'value dyn ::std::any::Any,
+    ) -> Option<&'value dyn MetaInstance> {
+        self.dyn_wrap_ref(value)
+    }
+}
+#[automatically_derived]
+impl<Factory: Meta> ::dyn_std::registry::Construct<Factory> for dyn MetaConstructor {
+    #[inline]
+    fn construct() -> Box<Self> {
+        Box::new(::dyn_std::Constructor::<Factory>::new())
+    }
+}
+#[automatically_derived]
+impl<Factory: Meta> ::dyn_std::registry::Construct<Factory>
+for dyn MetaConstructor + Send + Sync {
+    #[inline]
+    fn construct() -> Box<Self> {
+        Box::new(::dyn_std::Constructor::<Factory>::new())
+    }
+}
+#[automatically_derived]
+impl ::dyn_std::Downcast for dyn MetaInstance {
+    #[inline]
+    fn type_name_of_inner(&self) -> &'static str {
+        self.dyn_type_name_of_inner()
+    }
+    #[inline]
+    fn type_id_of_inner(&self) -> ::std::any::TypeId {
+        self.dyn_type_id_of_inner()
+    }
+}
+impl dyn MetaInstance {
+    /// Returns the constructor of the implementation, or `None` if it is not an `Instance<T>`.
+    #[inline]
+    pub fn constructor(&self) -> Option<&'static dyn MetaConstructor> {
+        self.dyn_constructor()
+    }
+}
+#[automatically_derived]
+impl ::dyn_std::Downcast for dyn MetaInstance + Send {
+    #[inline]
+    fn type_name_of_inner(&self) -> &'static str {
+        self.dyn_type_name_of_inner()
+    }
+    #[inline]
+    fn type_id_of_inner(&self) -> ::std::any::TypeId {
+        self.dyn_type_id_of_inner()
+    }
+}
+impl dyn MetaInstance + Send {
+    /// Returns the constructor of the implementation, or `None` if it is not an `Instance<T>`.
+    #[inline]
+    pub fn constructor(&self) -> Option<&'static dyn MetaConstructor> {
+        self.dyn_constructor()
+    }
+}
+#[automatically_derived]
+impl ::dyn_std::Downcast for dyn MetaInstance + Send + Sync {
+    #[inline]
+    fn type_name_of_inner(&self) -> &'static str {
+        self.dyn_type_name_of_inner()
+    }
+    #[inline]
+    fn type_id_of_inner(&self) -> ::std::any::TypeId {
+        self.dyn_type_id_of_inner()
+    }
+}
+impl dyn MetaInstance + Send + Sync {
+    /// Returns the constructor of the implementation, or `None` if it is not an `Instance<T>`.
+    #[inline]
+    pub fn constructor(&self) -> Option<&'static dyn MetaConstructor> {
+        self.dyn_constructor()
+    }
+}
+#[automatically_derived]
+impl<Factory: Meta> ::dyn_std::adapt::Wrap<Factory> for dyn MetaInstance {
+    #[inline]
+    fn wrap(value: Factory) -> Box<Self> {
+        Box::new(::dyn_std::Instance::new(value))
+    }
+    #[inline]
+    fn wrap_ref(value: &Factory) -> &Self {
+        ::dyn_std::Instance::from_ref(value)
+    }
+    #[inline]
+    fn wrap_mut(value: &mut Factory) -> &mut Self {
+        ::dyn_std::Instance::from_mut(value)
+    }
+}
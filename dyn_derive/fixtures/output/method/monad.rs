@@ -5,7 +5,20 @@ trait Meta<T: 'static>: Sized + 'static {
     fn vec(arg: Vec<Self>);
     fn nested(arg: Vec<(Self, Option<Option<Self>>)>);
 }
-trait MetaInstance<T>: ::dyn_std::any::Dyn {}
+trait MetaInstance<T>: ::dyn_std::any::Dyn {
+    #[doc(hidden)]
+    fn dyn_type_name_of_inner(&self) -> &'static str {
+        ::dyn_std::Dyn::type_name(self)
+    }
+    #[doc(hidden)]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId {
+        ::std::any::Any::type_id(self)
+    }
+    #[doc(hidden)]
+    fn dyn_constructor(&self) -> Option<&'static dyn MetaConstructor<T>> {
+        None
+    }
+}
 trait MetaConstructor<T> {
     fn option(&self, arg: Option<Box<dyn MetaInstance<T>>>);
     fn result_1(&self, arg: Result<Box<dyn MetaInstance<T>>, ()>);
@@ -15,9 +28,50 @@ trait MetaConstructor<T> {
         &self,
         arg: Vec<(Box<dyn MetaInstance<T>>, Option<Option<Box<dyn MetaInstance<T>>>>)>,
     );
+    /// Wraps a value of the implementation into an instance, or gives it back if it is of another type.
+    fn dyn_wrap(
+        &self,
+        value: Box<dyn ::std::any::Any>,
+    ) -> Result<Box<dyn MetaInstance<T>>, Box<dyn ::std::any::Any>> {
+        Err(value)
+    }
+    /// Wraps a reference to a value of the implementation into an instance, or returns `None` if it is of another type.
+    fn dyn_wrap_ref<'value>(
+        &self,
+        _value: &'value dyn ::std::any::Any,
+    ) -> Option<&'value dyn MetaInstance<T>> {
+        None
+    }
+    #[doc(hidden)]
+    fn dyn_type_name_of_inner(&self) -> &'static str
+    where
+        Self: 'static,
+    {
+        ::std::any::type_name::<Self>()
+    }
+    #[doc(hidden)]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId
+    where
+        Self: 'static,
+    {
+        ::std::any::TypeId::of::<Self>()
+    }
 }
 #[automatically_derived]
-impl<T: 'static, Factory: Meta<T>> MetaInstance<T> for ::dyn_std::Instance<Factory> {}
+impl<T: 'static, Factory: Meta<T>> MetaInstance<T> for ::dyn_std::Instance<Factory> {
+    #[inline]
+    fn dyn_type_name_of_inner(&self) -> &'static str {
+        ::std::any::type_name::<Factory>()
+    }
+    #[inline]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Factory>()
+    }
+    #[inline]
+    fn dyn_constructor(&self) -> Option<&'static dyn MetaConstructor<T>> {
+        Some(const { &::dyn_std::Constructor::<Factory>::new() })
+    }
+}
 #[automatically_derived]
 impl<T: 'static, Factory: Meta<T>> MetaConstructor<T>
 for ::dyn_std::Constructor<Factory> {
@@ -25,7 +79,7 @@ for ::dyn_std::Constructor<Factory> {
     fn option(&self, a1: Option<Box<dyn MetaInstance<T>>>) {
         let a1 = ::dyn_std::map::Map1::map(
             a1,
-            |x: Box<dyn MetaInstance<T>>| -> Factory {
+            &mut |x: Box<dyn MetaInstance<T>>| -> Factory {
                 ::dyn_std::Instance::<Factory>::downcast(x)
             },
         );
@@ -35,10 +89,10 @@ for ::dyn_std::Constructor<Factory> {
     fn result_1(&self, a1: Result<Box<dyn MetaInstance<T>>, ()>) {
         let a1 = ::dyn_std::map::Map2::map(
             a1,
-            |x: Box<dyn MetaInstance<T>>| -> Factory {
+            &mut |x: Box<dyn MetaInstance<T>>| -> Factory {
                 ::dyn_std::Instance::<Factory>::downcast(x)
             },
-            |x: ()| x,
+            &mut |x: ()| x,
         );
         Factory::result_1(a1)
     }
@@ -46,8 +100,8 @@ for ::dyn_std::Constructor<Factory> {
     fn result_2(&self, a1: Result<(), Box<dyn MetaInstance<T>>>) {
         let a1 = ::dyn_std::map::Map2::map(
             a1,
-            |x: ()| x,
-            |x: Box<dyn MetaInstance<T>>| -> Factory {
+            &mut |x: ()| x,
+            &mut |x: Box<dyn MetaInstance<T>>| -> Factory {
                 ::dyn_std::Instance::<Factory>::downcast(x)
             },
         );
@@ -57,7 +111,7 @@ for ::dyn_std::Constructor<Factory> {
     fn vec(&self, a1: Vec<Box<dyn MetaInstance<T>>>) {
         let a1 = ::dyn_std::map::Map1::map(
             a1,
-            |x: Box<dyn MetaInstance<T>>| -> Factory {
+            &mut |x: Box<dyn MetaInstance<T>>| -> Factory {
                 ::dyn_std::Instance::<Factory>::downcast(x)
             },
         );
@@ -70,7 +124,7 @@ for ::dyn_std::Constructor<Factory> {
     ) {
         let a1 = ::dyn_std::map::Map1::map(
             a1,
-            |
+            &mut |
                 (
                     b1,
                     b2,
@@ -79,10 +133,10 @@ for ::dyn_std::Constructor<Factory> {
                 let b1 = ::dyn_std::Instance::<Factory>::downcast(b1);
                 let b2 = ::dyn_std::map::Map1::map(
                     b2,
-                    |x: Option<Box<dyn MetaInstance<T>>>| -> Option<Factory> {
+                    &mut |x: Option<Box<dyn MetaInstance<T>>>| -> Option<Factory> {
                         ::dyn_std::map::Map1::map(
                             x,
-                            |x: Box<dyn MetaInstance<T>>| -> Factory {
+                            &mut |x: Box<dyn MetaInstance<T>>| -> Factory {
                                 ::dyn_std::Instance::<Factory>::downcast(x)
                             },
                         )
@@ -93,4 +147,188 @@ for ::dyn_std::Constructor<Factory> {
         );
         Factory::nested(a1)
     }
+    #[inline]
+    fn dyn_wrap(
+        &self,
+        value: Box<dyn ::std::any::Any>,
+    ) -> Result<Box<dyn MetaInstance<T>>, Box<dyn ::std::any::Any>> {
+        Ok(Box::new(::dyn_std::Instance::new(*value.downcast::<Factory>()?)))
+    }
+    #[inline]
+    fn dyn_wrap_ref<'value>(
+        &self,
+        value: &'value dyn ::std::any::Any,
+    ) -> Option<&'value dyn MetaInstance<T>> {
+        Some(::dyn_std::Instance::from_ref(value.downcast_ref::<Factory>()?))
+    }
+    #[inline]
+    fn dyn_type_name_of_inner(&self) -> &'static str {
+        ::std::any::type_name::<Factory>()
+    }
+    #[inline]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Factory>()
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::core::fmt::Debug for dyn MetaConstructor<T> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(f, "Constructor<{}>", self.dyn_type_name_of_inner())
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::core::cmp::PartialEq for dyn MetaConstructor<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_type_id_of_inner() == other.dyn_type_id_of_inner()
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::core::cmp::Eq for dyn MetaConstructor<T> {}
+#[automatically_derived]
+impl<T: 'static> ::core::hash::Hash for dyn MetaConstructor<T> {
+    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+        self.dyn_type_id_of_inner().hash(state)
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::core::fmt::Debug for dyn MetaConstructor<T> + Send + Sync {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(f, "Constructor<{}>", self.dyn_type_name_of_inner())
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::core::cmp::PartialEq for dyn MetaConstructor<T> + Send + Sync {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_type_id_of_inner() == other.dyn_type_id_of_inner()
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::core::cmp::Eq for dyn MetaConstructor<T> + Send + Sync {}
+#[automatically_derived]
+impl<T: 'static> ::core::hash::Hash for dyn MetaConstructor<T> + Send + Sync {
+    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+        self.dyn_type_id_of_inner().hash(state)
+    }
+}
+impl<T: 'static> dyn MetaConstructor<T> {
+    /// Wraps a value of the implementation into an instance, or gives it back if it is of another type.
+    #[inline]
+    pub fn wrap(
+        &self,
+        value: Box<dyn ::std::any::Any>,
+    ) -> Result<Box<dyn MetaInstance<T>>, Box<dyn ::std::any::Any>> {
+        self.dyn_wrap(value)
+    }
+    /// Wraps a reference to a value of the implementation into an instance, or returns `None` if it is of another type.
+    #[inline]
+    pub fn wrap_ref<'value>(
+        &self,
+        value: &'value dyn ::std::any::Any,
+    ) -> Option<&'value dyn MetaInstance<T>> {
+        self.dyn_wrap_ref(value)
+    }
+}
+impl<T: 'static> dyn MetaConstructor<T> + Send + Sync {
+    /// Wraps a value of the implementation into an instance, or gives it back if it is of another type.
+    #[inline]
+    pub fn wrap(
+        &self,
+        value: Box<dyn ::std::any::Any>,
+    ) -> Result<Box<dyn MetaInstance<T>>, Box<dyn ::std::any::Any>> {
+        self.dyn_wrap(value)
+    }
+    /// Wraps a reference to a value of the implementation into an instance, or returns `None` if it is of another type.
+    #[inline]
+    pub fn wrap_ref<'value>(
+        &self,
+        value: &'value dyn ::std::any::Any,
+    ) -> Option<&'value dyn MetaInstance<T>> {
+        self.dyn_wrap_ref(value)
+    }
+}
+#[automatically_derived]
+impl<T: 'static, Factory: Meta<T>> ::dyn_std::registry::Construct<Factory>
+for dyn MetaConstructor<T> {
+    #[inline]
+    fn construct() -> Box<Self> {
+        Box::new(::dyn_std::Constructor::<Factory>::new())
+    }
+}
+#[automatically_derived]
+impl<T: 'static, Factory: Meta<T>> ::dyn_std::registry::Construct<Factory>
+for dyn MetaConstructor<T> + Send + Sync {
+    #[inline]
+    fn construct() -> Box<Self> {
+        Box::new(::dyn_std::Constructor::<Factory>::new())
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::dyn_std::Downcast for dyn MetaInstance<T> {
+    #[inline]
+    fn type_name_of_inner(&self) -> &'static str {
+        self.dyn_type_name_of_inner()
+    }
+    #[inline]
+    fn type_id_of_inner(&self) -> ::std::any::TypeId {
+        self.dyn_type_id_of_inner()
+    }
+}
+impl<T: 'static> dyn MetaInstance<T> {
+    /// Returns the constructor of the implementation, or `None` if it is not an `Instance<T>`.
+    #[inline]
+    pub fn constructor(&self) -> Option<&'static dyn MetaConstructor<T>> {
+        self.dyn_constructor()
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::dyn_std::Downcast for dyn MetaInstance<T> + Send {
+    #[inline]
+    fn type_name_of_inner(&self) -> &'static str {
+        self.dyn_type_name_of_inner()
+    }
+    #[inline]
+    fn type_id_of_inner(&self) -> ::std::any::TypeId {
+        self.dyn_type_id_of_inner()
+    }
+}
+impl<T: 'static> dyn MetaInstance<T> + Send {
+    /// Returns the constructor of the implementation, or `None` if it is not an `Instance<T>`.
+    #[inline]
+    pub fn constructor(&self) -> Option<&'static dyn MetaConstructor<T>> {
+        self.dyn_constructor()
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::dyn_std::Downcast for dyn MetaInstance<T> + Send + Sync {
+    #[inline]
+    fn type_name_of_inner(&self) -> &'static str {
+        self.dyn_type_name_of_inner()
+    }
+    #[inline]
+    fn type_id_of_inner(&self) -> ::std::any::TypeId {
+        self.dyn_type_id_of_inner()
+    }
+}
+impl<T: 'static> dyn MetaInstance<T> + Send + Sync {
+    /// Returns the constructor of the implementation, or `None` if it is not an `Instance<T>`.
+    #[inline]
+    pub fn constructor(&self) -> Option<&'static dyn MetaConstructor<T>> {
+        self.dyn_constructor()
+    }
+}
+#[automatically_derived]
+impl<T: 'static, Factory: Meta<T>> ::dyn_std::adapt::Wrap<Factory>
+for dyn MetaInstance<T> {
+    #[inline]
+    fn wrap(value: Factory) -> Box<Self> {
+        Box::new(::dyn_std::Instance::new(value))
+    }
+    #[inline]
+    fn wrap_ref(value: &Factory) -> &Self {
+        ::dyn_std::Instance::from_ref(value)
+    }
+    #[inline]
+    fn wrap_mut(value: &mut Factory) -> &mut Self {
+        ::dyn_std::Instance::from_mut(value)
+    }
 }
@@ -0,0 +1,292 @@
+trait Meta<T: 'static>: Sized + 'static {
+    fn method_1(arg: *const T);
+    fn method_2(&mut self, peer: *mut Self);
+    fn method_3(arg: (*const Self, T)) -> *mut Self;
+    fn method_4(arg: &dyn Fn(*mut Self));
+}
+trait MetaInstance<T>: ::dyn_std::any::Dyn {
+    /// # Safety
+    ///
+    /// Raw pointers to `Self` must point to the same implementation.
+    unsafe fn method_2(&mut self, peer: *mut dyn MetaInstance<T>);
+    #[doc(hidden)]
+    fn dyn_type_name_of_inner(&self) -> &'static str {
+        ::dyn_std::Dyn::type_name(self)
+    }
+    #[doc(hidden)]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId {
+        ::std::any::Any::type_id(self)
+    }
+    #[doc(hidden)]
+    fn dyn_constructor(&self) -> Option<&'static dyn MetaConstructor<T>> {
+        None
+    }
+}
+trait MetaConstructor<T> {
+    fn method_1(&self, arg: *const T);
+    /// # Safety
+    ///
+    /// Raw pointers to `Self` must point to the same implementation.
+    unsafe fn method_3(
+        &self,
+        arg: (*const dyn MetaInstance<T>, T),
+    ) -> *mut dyn MetaInstance<T>;
+    fn method_4(&self, arg: &dyn Fn(*mut dyn MetaInstance<T>));
+    /// Wraps a value of the implementation into an instance, or gives it back if it is of another type.
+    fn dyn_wrap(
+        &self,
+        value: Box<dyn ::std::any::Any>,
+    ) -> Result<Box<dyn MetaInstance<T>>, Box<dyn ::std::any::Any>> {
+        Err(value)
+    }
+    /// Wraps a reference to a value of the implementation into an instance, or returns `None` if it is of another type.
+    fn dyn_wrap_ref<'value>(
+        &self,
+        _value: &'value dyn ::std::any::Any,
+    ) -> Option<&'value dyn MetaInstance<T>> {
+        None
+    }
+    #[doc(hidden)]
+    fn dyn_type_name_of_inner(&self) -> &'static str
+    where
+        Self: 'static,
+    {
+        ::std::any::type_name::<Self>()
+    }
+    #[doc(hidden)]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId
+    where
+        Self: 'static,
+    {
+        ::std::any::TypeId::of::<Self>()
+    }
+}
+#[automatically_derived]
+impl<T: 'static, Factory: Meta<T>> MetaInstance<T> for ::dyn_std::Instance<Factory> {
+    #[inline]
+    unsafe fn method_2(&mut self, a1: *mut dyn MetaInstance<T>) {
+        let a1 = a1 as *mut Factory;
+        self.0.method_2(a1)
+    }
+    #[inline]
+    fn dyn_type_name_of_inner(&self) -> &'static str {
+        ::std::any::type_name::<Factory>()
+    }
+    #[inline]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Factory>()
+    }
+    #[inline]
+    fn dyn_constructor(&self) -> Option<&'static dyn MetaConstructor<T>> {
+        Some(const { &::dyn_std::Constructor::<Factory>::new() })
+    }
+}
+#[automatically_derived]
+impl<T: 'static, Factory: Meta<T>> MetaConstructor<T>
+for ::dyn_std::Constructor<Factory> {
+    #[inline]
+    fn method_1(&self, a1: *const T) {
+        Factory::method_1(a1)
+    }
+    #[inline]
+    unsafe fn method_3(
+        &self,
+        (a1, a2): (*const dyn MetaInstance<T>, T),
+    ) -> *mut dyn MetaInstance<T> {
+        let a1 = a1 as *const Factory;
+        Factory::method_3((a1, a2)) as *mut ::dyn_std::Instance<Factory>
+            as *mut dyn MetaInstance<T>
+    }
+    #[inline]
+    fn method_4(&self, a1: &dyn Fn(*mut dyn MetaInstance<T>)) {
+        let a1 = &|b1| {
+            let b1 = b1 as *mut ::dyn_std::Instance<Factory> as *mut dyn MetaInstance<T>;
+            a1(b1)
+        };
+        Factory::method_4(a1)
+    }
+    #[inline]
+    fn dyn_wrap(
+        &self,
+        value: Box<dyn ::std::any::Any>,
+    ) -> Result<Box<dyn MetaInstance<T>>, Box<dyn ::std::any::Any>> {
+        Ok(Box::new(::dyn_std::Instance::new(*value.downcast::<Factory>()?)))
+    }
+    #[inline]
+    fn dyn_wrap_ref<'value>(
+        &self,
+        value: &'value dyn ::std::any::Any,
+    ) -> Option<&'value dyn MetaInstance<T>> {
+        Some(::dyn_std::Instance::from_ref(value.downcast_ref::<Factory>()?))
+    }
+    #[inline]
+    fn dyn_type_name_of_inner(&self) -> &'static str {
+        ::std::any::type_name::<Factory>()
+    }
+    #[inline]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Factory>()
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::core::fmt::Debug for dyn MetaConstructor<T> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(f, "Constructor<{}>", self.dyn_type_name_of_inner())
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::core::cmp::PartialEq for dyn MetaConstructor<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_type_id_of_inner() == other.dyn_type_id_of_inner()
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::core::cmp::Eq for dyn MetaConstructor<T> {}
+#[automatically_derived]
+impl<T: 'static> ::core::hash::Hash for dyn MetaConstructor<T> {
+    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+        self.dyn_type_id_of_inner().hash(state)
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::core::fmt::Debug for dyn MetaConstructor<T> + Send + Sync {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(f, "Constructor<{}>", self.dyn_type_name_of_inner())
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::core::cmp::PartialEq for dyn MetaConstructor<T> + Send + Sync {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_type_id_of_inner() == other.dyn_type_id_of_inner()
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::core::cmp::Eq for dyn MetaConstructor<T> + Send + Sync {}
+#[automatically_derived]
+impl<T: 'static> ::core::hash::Hash for dyn MetaConstructor<T> + Send + Sync {
+    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+        self.dyn_type_id_of_inner().hash(state)
+    }
+}
+impl<T: 'static> dyn MetaConstructor<T> {
+    /// Wraps a value of the implementation into an instance, or gives it back if it is of another type.
+    #[inline]
+    pub fn wrap(
+        &self,
+        value: Box<dyn ::std::any::Any>,
+    ) -> Result<Box<dyn MetaInstance<T>>, Box<dyn ::std::any::Any>> {
+        self.dyn_wrap(value)
+    }
+    /// Wraps a reference to a value of the implementation into an instance, or returns `None` if it is of another type.
+    #[inline]
+    pub fn wrap_ref<'value>(
+        &self,
+        value: &'value dyn ::std::any::Any,
+    ) -> Option<&'value dyn MetaInstance<T>> {
+        self.dyn_wrap_ref(value)
+    }
+}
+impl<T: 'static> dyn MetaConstructor<T> + Send + Sync {
+    /// Wraps a value of the implementation into an instance, or gives it back if it is of another type.
+    #[inline]
+    pub fn wrap(
+        &self,
+        value: Box<dyn ::std::any::Any>,
+    ) -> Result<Box<dyn MetaInstance<T>>, Box<dyn ::std::any::Any>> {
+        self.dyn_wrap(value)
+    }
+    /// Wraps a reference to a value of the implementation into an instance, or returns `None` if it is of another type.
+    #[inline]
+    pub fn wrap_ref<'value>(
+        &self,
+        value: &'value dyn ::std::any::Any,
+    ) -> Option<&'value dyn MetaInstance<T>> {
+        self.dyn_wrap_ref(value)
+    }
+}
+#[automatically_derived]
+impl<T: 'static, Factory: Meta<T>> ::dyn_std::registry::Construct<Factory>
+for dyn MetaConstructor<T> {
+    #[inline]
+    fn construct() -> Box<Self> {
+        Box::new(::dyn_std::Constructor::<Factory>::new())
+    }
+}
+#[automatically_derived]
+impl<T: 'static, Factory: Meta<T>> ::dyn_std::registry::Construct<Factory>
+for dyn MetaConstructor<T> + Send + Sync {
+    #[inline]
+    fn construct() -> Box<Self> {
+        Box::new(::dyn_std::Constructor::<Factory>::new())
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::dyn_std::Downcast for dyn MetaInstance<T> {
+    #[inline]
+    fn type_name_of_inner(&self) -> &'static str {
+        self.dyn_type_name_of_inner()
+    }
+    #[inline]
+    fn type_id_of_inner(&self) -> ::std::any::TypeId {
+        self.dyn_type_id_of_inner()
+    }
+}
+impl<T: 'static> dyn MetaInstance<T> {
+    /// Returns the constructor of the implementation, or `None` if it is not an `Instance<T>`.
+    #[inline]
+    pub fn constructor(&self) -> Option<&'static dyn MetaConstructor<T>> {
+        self.dyn_constructor()
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::dyn_std::Downcast for dyn MetaInstance<T> + Send {
+    #[inline]
+    fn type_name_of_inner(&self) -> &'static str {
+        self.dyn_type_name_of_inner()
+    }
+    #[inline]
+    fn type_id_of_inner(&self) -> ::std::any::TypeId {
+        self.dyn_type_id_of_inner()
+    }
+}
+impl<T: 'static> dyn MetaInstance<T> + Send {
+    /// Returns the constructor of the implementation, or `None` if it is not an `Instance<T>`.
+    #[inline]
+    pub fn constructor(&self) -> Option<&'static dyn MetaConstructor<T>> {
+        self.dyn_constructor()
+    }
+}
+#[automatically_derived]
+impl<T: 'static> ::dyn_std::Downcast for dyn MetaInstance<T> + Send + Sync {
+    #[inline]
+    fn type_name_of_inner(&self) -> &'static str {
+        self.dyn_type_name_of_inner()
+    }
+    #[inline]
+    fn type_id_of_inner(&self) -> ::std::any::TypeId {
+        self.dyn_type_id_of_inner()
+    }
+}
+impl<T: 'static> dyn MetaInstance<T> + Send + Sync {
+    /// Returns the constructor of the implementation, or `None` if it is not an `Instance<T>`.
+    #[inline]
+    pub fn constructor(&self) -> Option<&'static dyn MetaConstructor<T>> {
+        self.dyn_constructor()
+    }
+}
+#[automatically_derived]
+impl<T: 'static, Factory: Meta<T>> ::dyn_std::adapt::Wrap<Factory>
+for dyn MetaInstance<T> {
+    #[inline]
+    fn wrap(value: Factory) -> Box<Self> {
+        Box::new(::dyn_std::Instance::new(value))
+    }
+    #[inline]
+    fn wrap_ref(value: &Factory) -> &Self {
+        ::dyn_std::Instance::from_ref(value)
+    }
+    #[inline]
+    fn wrap_mut(value: &mut Factory) -> &mut Self {
+        ::dyn_std::Instance::from_mut(value)
+    }
+}
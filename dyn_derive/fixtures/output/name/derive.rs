@@ -0,0 +1,4 @@
+#[automatically_derived]
+impl ::dyn_std::DynName for Circle {
+    const NAME: &'static str = "geo.circle/v1";
+}
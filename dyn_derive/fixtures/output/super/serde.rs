@@ -0,0 +1,315 @@
+pub trait Shape: serde::Serialize + serde::de::DeserializeOwned + Sized + ::dyn_std::DynName + 'static {
+    fn area(&self) -> f64;
+}
+pub trait ShapeInstance: ::dyn_std::serde::Serialize + ::dyn_std::any::Dyn {
+    fn area(&self) -> f64;
+    #[doc(hidden)]
+    fn dyn_type_name_of_inner(&self) -> &'static str {
+        ::dyn_std::Dyn::type_name(self)
+    }
+    #[doc(hidden)]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId {
+        ::std::any::Any::type_id(self)
+    }
+    #[doc(hidden)]
+    fn dyn_constructor(&self) -> Option<&'static dyn ShapeConstructor> {
+        None
+    }
+}
+pub trait ShapeConstructor: ::dyn_std::serde::Deserialize<dyn ShapeInstance> {
+    /// Wraps a value of the implementation into an instance, or gives it back if it is of another type.
+    fn dyn_wrap(
+        &self,
+        value: Box<dyn ::std::any::Any>,
+    ) -> Result<Box<dyn ShapeInstance>, Box<dyn ::std::any::Any>> {
+        Err(value)
+    }
+    /// Wraps a reference to a value of the implementation into an instance, or returns `None` if it is of another type.
+    fn dyn_wrap_ref<'value>(
+        &self,
+        _value: &'value dyn ::std::any::Any,
+    ) -> Option<&'value dyn ShapeInstance> {
+        None
+    }
+    #[doc(hidden)]
+    fn dyn_type_name_of_inner(&self) -> &'static str
+    where
+        Self: 'static,
+    {
+        ::std::any::type_name::<Self>()
+    }
+    #[doc(hidden)]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId
+    where
+        Self: 'static,
+    {
+        ::std::any::TypeId::of::<Self>()
+    }
+}
+#[automatically_derived]
+impl ::dyn_std::serde::serde::Serialize for dyn ShapeInstance {
+    #[inline]
+    fn serialize<S: ::dyn_std::serde::serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        ::dyn_std::serde::serialize(self, Some("type"), serializer)
+    }
+}
+#[automatically_derived]
+impl<'de> ::dyn_std::serde::serde::Deserialize<'de> for Box<dyn ShapeInstance> {
+    #[inline]
+    fn deserialize<D: ::dyn_std::serde::serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let registry = ::dyn_std::registry::Registry::<
+            dyn ShapeConstructor + Send + Sync,
+        >::global()
+            .map_err(<D::Error as ::dyn_std::serde::serde::de::Error>::custom)?;
+        ::dyn_std::serde::deserialize(
+            |name| Some(registry.read().get(name)?.as_static()),
+            Some("type"),
+            deserializer,
+        )
+    }
+}
+#[automatically_derived]
+impl<Factory: Shape> ShapeInstance for ::dyn_std::Instance<Factory> {
+    #[inline]
+    fn area(&self) -> f64 {
+        self.0.area()
+    }
+    #[inline]
+    fn dyn_type_name_of_inner(&self) -> &'static str {
+        ::std::any::type_name::<Factory>()
+    }
+    #[inline]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Factory>()
+    }
+    #[inline]
+    fn dyn_constructor(&self) -> Option<&'static dyn ShapeConstructor> {
+        Some(const { &::dyn_std::Constructor::<Factory>::new() })
+    }
+}
+#[automatically_derived]
+impl<Factory: Shape> ShapeConstructor for ::dyn_std::Constructor<Factory> {
+    #[inline]
+    fn dyn_wrap(
+        &self,
+        value: Box<dyn ::std::any::Any>,
+    ) -> Result<Box<dyn ShapeInstance>, Box<dyn ::std::any::Any>> {
+        Ok(Box::new(::dyn_std::Instance::new(*value.downcast::<Factory>()?)))
+    }
+    #[inline]
+    fn dyn_wrap_ref<'value>(
+        &self,
+        value: &'value dyn ::std::any::Any,
+    ) -> Option<&'value dyn ShapeInstance> {
+        Some(::dyn_std::Instance::from_ref(value.downcast_ref::<Factory>()?))
+    }
+    #[inline]
+    fn dyn_type_name_of_inner(&self) -> &'static str {
+        ::std::any::type_name::<Factory>()
+    }
+    #[inline]
+    fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Factory>()
+    }
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for dyn ShapeConstructor {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(f, "Constructor<{}>", self.dyn_type_name_of_inner())
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for dyn ShapeConstructor {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_type_id_of_inner() == other.dyn_type_id_of_inner()
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for dyn ShapeConstructor {}
+#[automatically_derived]
+impl ::core::hash::Hash for dyn ShapeConstructor {
+    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+        self.dyn_type_id_of_inner().hash(state)
+    }
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for dyn ShapeConstructor + Send + Sync {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(f, "Constructor<{}>", self.dyn_type_name_of_inner())
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for dyn ShapeConstructor + Send + Sync {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_type_id_of_inner() == other.dyn_type_id_of_inner()
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for dyn ShapeConstructor + Send + Sync {}
+#[automatically_derived]
+impl ::core::hash::Hash for dyn ShapeConstructor + Send + Sync {
+    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+        self.dyn_type_id_of_inner().hash(state)
+    }
+}
+impl dyn ShapeConstructor {
+    /// Wraps a value of the implementation into an instance, or gives it back if it is of another type.
+    #[inline]
+    pub fn wrap(
+        &self,
+        value: Box<dyn ::std::any::Any>,
+    ) -> Result<Box<dyn ShapeInstance>, Box<dyn ::std::any::Any>> {
+        self.dyn_wrap(value)
+    }
+    /// Wraps a reference to a value of the implementation into an instance, or returns `None` if it is of another type.
+    #[inline]
+    pub fn wrap_ref<'value>(
+        &self,
+        value: &'value dyn ::std::any::Any,
+    ) -> Option<&'value dyn ShapeInstance> {
+        self.dyn_wrap_ref(value)
+    }
+}
+impl dyn ShapeConstructor + Send + Sync {
+    /// Wraps a value of the implementation into an instance, or gives it back if it is of another type.
+    #[inline]
+    pub fn wrap(
+        &self,
+        value: Box<dyn ::std::any::Any>,
+    ) -> Result<Box<dyn ShapeInstance>, Box<dyn ::std::any::Any>> {
+        self.dyn_wrap(value)
+    }
+    /// Wraps a reference to a value of the implementation into an instance, or returns `None` if it is of another type.
+    #[inline]
+    pub fn wrap_ref<'value>(
+        &self,
+        value: &'value dyn ::std::any::Any,
+    ) -> Option<&'value dyn ShapeInstance> {
+        self.dyn_wrap_ref(value)
+    }
+}
+#[automatically_derived]
+impl<Factory: Shape> ::dyn_std::registry::Construct<Factory> for dyn ShapeConstructor {
+    #[inline]
+    fn construct() -> Box<Self> {
+        Box::new(::dyn_std::Constructor::<Factory>::new())
+    }
+}
+#[automatically_derived]
+impl<Factory: Shape> ::dyn_std::registry::Construct<Factory>
+for dyn ShapeConstructor + Send + Sync {
+    #[inline]
+    fn construct() -> Box<Self> {
+        Box::new(::dyn_std::Constructor::<Factory>::new())
+    }
+}
+pub type ShapeRegistry = ::dyn_std::registry::Registry<
+    dyn ShapeConstructor + Send + Sync,
+>;
+#[automatically_derived]
+impl ::dyn_std::registry::Global for dyn ShapeConstructor + Send + Sync {
+    fn global() -> Result<
+        &'static ::dyn_std::registry::SyncRegistry<Self>,
+        ::dyn_std::registry::Error,
+    > {
+        static REGISTRY: ::std::sync::OnceLock<
+            Result<
+                ::dyn_std::registry::SyncRegistry<dyn ShapeConstructor + Send + Sync>,
+                ::dyn_std::registry::Error,
+            >,
+        > = ::std::sync::OnceLock::new();
+        REGISTRY
+            .get_or_init(::dyn_std::registry::SyncRegistry::submitted)
+            .as_ref()
+            .map_err(Clone::clone)
+    }
+    fn submissions() -> &'static ::dyn_std::registry::Submissions<Self> {
+        static SUBMISSIONS: ::dyn_std::registry::Submissions<
+            dyn ShapeConstructor + Send + Sync,
+        > = ::dyn_std::registry::Submissions::new();
+        &SUBMISSIONS
+    }
+}
+/// Registers the constructor of `T` in the global [`ShapeRegistry`].
+pub fn register_shape<T: Shape>(
+    name: impl Into<String>,
+) -> Result<(), ::dyn_std::registry::Error>
+where
+    dyn ShapeConstructor + Send + Sync: ::dyn_std::registry::Construct<T>,
+{
+    ShapeRegistry::global()?.register::<T>(name)
+}
+#[automatically_derived]
+impl ::dyn_std::Downcast for dyn ShapeInstance {
+    #[inline]
+    fn type_name_of_inner(&self) -> &'static str {
+        self.dyn_type_name_of_inner()
+    }
+    #[inline]
+    fn type_id_of_inner(&self) -> ::std::any::TypeId {
+        self.dyn_type_id_of_inner()
+    }
+}
+impl dyn ShapeInstance {
+    /// Returns the constructor of the implementation, or `None` if it is not an `Instance<T>`.
+    #[inline]
+    pub fn constructor(&self) -> Option<&'static dyn ShapeConstructor> {
+        self.dyn_constructor()
+    }
+}
+#[automatically_derived]
+impl ::dyn_std::Downcast for dyn ShapeInstance + Send {
+    #[inline]
+    fn type_name_of_inner(&self) -> &'static str {
+        self.dyn_type_name_of_inner()
+    }
+    #[inline]
+    fn type_id_of_inner(&self) -> ::std::any::TypeId {
+        self.dyn_type_id_of_inner()
+    }
+}
+impl dyn ShapeInstance + Send {
+    /// Returns the constructor of the implementation, or `None` if it is not an `Instance<T>`.
+    #[inline]
+    pub fn constructor(&self) -> Option<&'static dyn ShapeConstructor> {
+        self.dyn_constructor()
+    }
+}
+#[automatically_derived]
+impl ::dyn_std::Downcast for dyn ShapeInstance + Send + Sync {
+    #[inline]
+    fn type_name_of_inner(&self) -> &'static str {
+        self.dyn_type_name_of_inner()
+    }
+    #[inline]
+    fn type_id_of_inner(&self) -> ::std::any::TypeId {
+        self.dyn_type_id_of_inner()
+    }
+}
+impl dyn ShapeInstance + Send + Sync {
+    /// Returns the constructor of the implementation, or `None` if it is not an `Instance<T>`.
+    #[inline]
+    pub fn constructor(&self) -> Option<&'static dyn ShapeConstructor> {
+        self.dyn_constructor()
+    }
+}
+#[automatically_derived]
+impl<Factory: Shape> ::dyn_std::adapt::Wrap<Factory> for dyn ShapeInstance {
+    #[inline]
+    fn wrap(value: Factory) -> Box<Self> {
+        Box::new(::dyn_std::Instance::new(value))
+    }
+    #[inline]
+    fn wrap_ref(value: &Factory) -> &Self {
+        ::dyn_std::Instance::from_ref(value)
+    }
+    #[inline]
+    fn wrap_mut(value: &mut Factory) -> &mut Self {
+        ::dyn_std::Instance::from_mut(value)
+    }
+}
@@ -0,0 +1,93 @@
+use proc_macro2::TokenStream;
+use syn::parse::Parser;
+
+/// Arguments of the `#[dyn_trait]` attribute.
+#[derive(Default)]
+pub struct Args {
+    /// Name of the instance trait, defaults to `{Name}Instance`.
+    pub instance: Option<syn::Ident>,
+    /// Name of the constructor trait, defaults to `{Name}Constructor`.
+    pub constructor: Option<syn::Ident>,
+    /// Visibility of the generated items, defaults to that of the original trait.
+    pub vis: Option<syn::Visibility>,
+    /// Name of the `Box<dyn {Instance}>` alias, not generated by default.
+    pub alias: Option<syn::Ident>,
+    /// Only generate the instance trait.
+    pub instance_only: bool,
+    /// Only generate the constructor trait.
+    pub constructor_only: bool,
+    /// Auto traits (`Send` and `Sync`) required by the generated traits.
+    pub auto_traits: Vec<syn::Ident>,
+    /// Generate a `{Name}Registry` alias and a global registry.
+    pub registry: bool,
+    /// Key of the internal type tag in serialization, externally tagged by default.
+    pub tag: Option<syn::LitStr>,
+    /// Whether to `"panic"` (default) or return an `"error"` from fallible methods,
+    /// when arguments of type `Self` are of other implementations.
+    pub on_mismatch: Option<syn::LitStr>,
+}
+
+fn set<T>(slot: &mut Option<T>, meta: &syn::meta::ParseNestedMeta) -> syn::Result<()>
+where
+    T: syn::parse::Parse,
+{
+    if slot.is_some() {
+        return Err(meta.error("duplicate argument"))
+    }
+    *slot = Some(meta.value()?.parse()?);
+    Ok(())
+}
+
+impl Args {
+    pub fn parse(attr: TokenStream) -> syn::Result<Self> {
+        let mut args = Self::default();
+        let parser = syn::meta::parser(|meta| {
+            if meta.path.is_ident("instance") {
+                set(&mut args.instance, &meta)
+            } else if meta.path.is_ident("constructor") {
+                set(&mut args.constructor, &meta)
+            } else if meta.path.is_ident("vis") {
+                set(&mut args.vis, &meta)
+            } else if meta.path.is_ident("alias") {
+                set(&mut args.alias, &meta)
+            } else if meta.path.is_ident("instance_only") || meta.path.is_ident("constructor_only") {
+                if args.instance_only || args.constructor_only {
+                    return Err(meta.error("only one of `instance_only` and `constructor_only` can be specified"))
+                }
+                args.instance_only = meta.path.is_ident("instance_only");
+                args.constructor_only = meta.path.is_ident("constructor_only");
+                Ok(())
+            } else if meta.path.is_ident("Send") || meta.path.is_ident("Sync") {
+                let ident = meta.path.get_ident().unwrap();
+                if args.auto_traits.contains(ident) {
+                    return Err(meta.error("duplicate argument"))
+                }
+                args.auto_traits.push(ident.clone());
+                Ok(())
+            } else if meta.path.is_ident("tag") {
+                set(&mut args.tag, &meta)
+            } else if meta.path.is_ident("on_mismatch") {
+                set(&mut args.on_mismatch, &meta)?;
+                match args.on_mismatch.as_ref().unwrap().value().as_str() {
+                    "panic" | "error" => Ok(()),
+                    _ => Err(syn::Error::new_spanned(&args.on_mismatch, "expected `\"panic\"` or `\"error\"`")),
+                }
+            } else if meta.path.is_ident("registry") {
+                if args.registry {
+                    return Err(meta.error("duplicate argument"))
+                }
+                args.registry = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported argument, expected `instance`, `constructor`, `vis`, `alias`, `instance_only`, `constructor_only`, `Send`, `Sync`, `registry`, `tag` or `on_mismatch`"))
+            }
+        });
+        parser.parse2(attr)?;
+        Ok(args)
+    }
+
+    /// Whether fallible methods return errors on mismatched implementations.
+    pub fn on_mismatch_error(&self) -> bool {
+        self.on_mismatch.as_ref().is_some_and(|value| value.value() == "error")
+    }
+}
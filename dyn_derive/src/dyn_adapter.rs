@@ -0,0 +1,192 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+
+use crate::errors::Errors;
+
+struct Param {
+    ident: syn::Ident,
+    dyn_ident: syn::Ident,
+}
+
+fn contains(tokens: TokenStream, params: &[Param]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => params.iter().any(|param| param.ident == ident),
+        TokenTree::Group(group) => contains(group.stream(), params),
+        _ => false,
+    })
+}
+
+fn find<'i>(ty: &syn::Type, params: &'i [Param]) -> Option<&'i Param> {
+    let syn::Type::Path(tp) = ty else {
+        return None
+    };
+    params.iter().find(|param| tp.qself.is_none() && tp.path.is_ident(&param.ident))
+}
+
+fn find_box<'i>(ty: &syn::Type, params: &'i [Param]) -> Option<&'i Param> {
+    let syn::Type::Path(tp) = ty else {
+        return None
+    };
+    if tp.qself.is_some() || tp.path.segments.len() != 1 {
+        return None
+    }
+    let last = tp.path.segments.last().unwrap();
+    if last.ident != "Box" {
+        return None
+    }
+    let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+        return None
+    };
+    let Some(syn::GenericArgument::Type(ty)) = args.args.first() else {
+        return None
+    };
+    find(ty, params)
+}
+
+fn wrap(ty: &syn::Type, expr: &impl ToTokens, params: &[Param], errors: &Errors) -> TokenStream {
+    if let syn::Type::Reference(reference) = ty {
+        if let Some(Param { ident, dyn_ident }) = find(&reference.elem, params) {
+            return match reference.mutability {
+                Some(_) => quote! { <#dyn_ident as ::dyn_std::adapt::Wrap<#ident>>::wrap_mut(#expr) },
+                None => quote! { <#dyn_ident as ::dyn_std::adapt::Wrap<#ident>>::wrap_ref(#expr) },
+            }
+        }
+    }
+    if let Some(Param { ident, dyn_ident }) = find_box(ty, params) {
+        return quote! { <#dyn_ident as ::dyn_std::adapt::Wrap<#ident>>::wrap(*#expr) }
+    }
+    if contains(ty.to_token_stream(), params) {
+        errors.push(ty, "dynamic parameters are only supported as `&T`, `&mut T` or `Box<T>` in adapter traits");
+    }
+    expr.to_token_stream()
+}
+
+fn unwrap(ty: &syn::Type, expr: &impl ToTokens, params: &[Param], errors: &Errors) -> TokenStream {
+    if let syn::Type::Reference(reference) = ty {
+        if let Some(Param { ident, dyn_ident }) = find(&reference.elem, params) {
+            return match reference.mutability {
+                Some(_) => quote! { <#dyn_ident as ::dyn_std::adapt::Wrap<#ident>>::unwrap_mut(#expr) },
+                None => quote! { <#dyn_ident as ::dyn_std::adapt::Wrap<#ident>>::unwrap_ref(#expr) },
+            }
+        }
+    }
+    if let Some(Param { ident, dyn_ident }) = find_box(ty, params) {
+        return quote! { Box::new(<#dyn_ident as ::dyn_std::adapt::Wrap<#ident>>::unwrap(#expr)) }
+    }
+    if contains(ty.to_token_stream(), params) {
+        errors.push(ty, "dynamic parameters are only supported as `&T`, `&mut T` or `Box<T>` in adapter traits");
+    }
+    expr.to_token_stream()
+}
+
+pub fn transform(_attr: TokenStream, mut item: syn::ItemTrait) -> syn::Result<TokenStream> {
+    let errors = Errors::default();
+    let mut generics = item.generics.clone();
+    let mut params = vec![];
+    for param in item.generics.params.iter_mut() {
+        let syn::GenericParam::Type(param) = param else {
+            continue;
+        };
+        let index = param.attrs.iter().position(|attr| {
+            attr.meta.path().is_ident("dynamic")
+        });
+        let Some(index) = index else {
+            continue;
+        };
+        param.attrs.remove(index);
+        param.bounds.push(syn::parse_quote! { ?Sized });
+        params.push(Param {
+            dyn_ident: format_ident!("Dyn{}", param.ident),
+            ident: param.ident.clone(),
+        });
+    }
+    for param in generics.params.iter_mut() {
+        if let syn::GenericParam::Type(param) = param {
+            param.attrs.clear();
+        }
+    }
+    let ident = &item.ident;
+    let dyn_args = item.generics.params.iter().map(|param| {
+        match param {
+            syn::GenericParam::Type(param) => {
+                match params.iter().find(|p| p.ident == param.ident) {
+                    Some(param) => param.dyn_ident.to_token_stream(),
+                    None => param.ident.to_token_stream(),
+                }
+            },
+            syn::GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+            syn::GenericParam::Const(param) => param.ident.to_token_stream(),
+        }
+    });
+    let dyn_trait = quote! { dyn #ident<#(#dyn_args),*> + 'inner };
+    let mut is_mut = false;
+    let mut impl_items = vec![];
+    for trait_item in &item.items {
+        let syn::TraitItem::Fn(item_fn) = trait_item else {
+            errors.push(trait_item, "adapter traits can only contain methods");
+            continue;
+        };
+        let mut sig = item_fn.sig.clone();
+        match sig.receiver() {
+            Some(recv) if recv.reference.is_some() => is_mut |= recv.mutability.is_some(),
+            Some(recv) => {
+                errors.push(recv, "methods of adapter traits must take `&self` or `&mut self`");
+                continue;
+            },
+            None => {
+                errors.push(&sig, "methods of adapter traits must take `&self` or `&mut self`");
+                continue;
+            },
+        }
+        let mut stmts = quote![];
+        let args = sig.inputs.iter_mut().skip(1).enumerate().map(|(index, arg)| {
+            let syn::FnArg::Typed(arg) = arg else {
+                unreachable!()
+            };
+            let ident = format_ident!("a{}", index + 1);
+            *arg.pat = syn::parse_quote! { #ident };
+            let expr = wrap(&arg.ty, &ident, &params, &errors);
+            if ident != expr.to_string() {
+                stmts.extend(quote! { let #ident = #expr; });
+            }
+            ident
+        }).collect::<Vec<_>>();
+        let method = &sig.ident;
+        let expr = quote! { self.0.#method(#(#args),*) };
+        let expr = match &sig.output {
+            syn::ReturnType::Type(_, ty) => unwrap(ty, &expr, &params, &errors),
+            syn::ReturnType::Default => expr,
+        };
+        impl_items.push(quote! {
+            #[inline]
+            #sig {
+                #stmts
+                #expr
+            }
+        });
+    }
+    let (_, type_generics, _) = item.generics.split_for_impl();
+    generics.params.insert(0, syn::parse_quote! { 'inner });
+    for Param { dyn_ident, .. } in &params {
+        generics.params.push(syn::parse_quote! { #dyn_ident: ?Sized });
+    }
+    generics.params.push(syn::parse_quote! { Inner });
+    let where_clause = generics.make_where_clause();
+    for Param { ident, dyn_ident } in &params {
+        where_clause.predicates.push(syn::parse_quote! { #ident: 'static });
+        where_clause.predicates.push(syn::parse_quote! { #dyn_ident: ::dyn_std::adapt::Wrap<#ident> });
+    }
+    where_clause.predicates.push(match is_mut {
+        true => syn::parse_quote! { Inner: ::core::ops::DerefMut<Target = #dyn_trait> },
+        false => syn::parse_quote! { Inner: ::core::ops::Deref<Target = #dyn_trait> },
+    });
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    errors.check()?;
+    Ok(quote! {
+        #item
+        #[automatically_derived]
+        impl #impl_generics #ident #type_generics for ::dyn_std::adapt::Adapter<Inner> #where_clause {
+            #(#impl_items)*
+        }
+    })
+}
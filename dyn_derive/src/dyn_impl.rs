@@ -0,0 +1,64 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::parse::Parser;
+
+use crate::errors::Errors;
+
+fn parse_name(attr: TokenStream) -> syn::Result<Option<syn::LitStr>> {
+    let mut name = None;
+    let parser = syn::meta::parser(|meta| {
+        if !meta.path.is_ident("name") {
+            return Err(meta.error("unsupported argument, expected `name`"))
+        }
+        if name.is_some() {
+            return Err(meta.error("duplicate argument"))
+        }
+        name = Some(meta.value()?.parse()?);
+        Ok(())
+    });
+    parser.parse2(attr)?;
+    Ok(name)
+}
+
+pub fn transform(attr: TokenStream, item: syn::ItemImpl) -> syn::Result<TokenStream> {
+    let name = parse_name(attr)?;
+    let errors = Errors::default();
+    let Some((_, path, _)) = &item.trait_ else {
+        return Err(syn::Error::new_spanned(&item.self_ty, "`dyn_impl` can only be applied to trait implementations"))
+    };
+    if !item.generics.params.is_empty() {
+        errors.push(&item.generics, "`dyn_impl` is not supported for generic implementations");
+    }
+    let last = path.segments.last().unwrap();
+    if !last.arguments.is_none() {
+        errors.push(&last.arguments, "`dyn_impl` is not supported for generic traits");
+    }
+    errors.check()?;
+    // the registry alias is generated next to the trait by `#[dyn_trait(registry)]`
+    let mut registry = path.clone();
+    registry.segments.last_mut().unwrap().ident = format_ident!("{}Registry", last.ident);
+    let self_ty = &item.self_ty;
+    // duplicate names are reported by the registry when submissions are collected
+    let name = match name {
+        Some(name) => quote! { #name },
+        None => quote! { <#self_ty as ::dyn_std::DynName>::NAME },
+    };
+    Ok(quote! {
+        #item
+        const _: () = {
+            static SUBMISSION: ::dyn_std::registry::Submission<<#registry as ::dyn_std::registry::Collect>::Value> = ::dyn_std::registry::Submission::new::<#self_ty>(#name);
+            #[cfg(not(any(target_os = "linux", target_os = "android", target_os = "freebsd", target_os = "netbsd", target_os = "openbsd", target_os = "dragonfly", target_os = "illumos", target_os = "macos", target_os = "ios", windows)))]
+            compile_error!("`dyn_impl` is not supported on this target, as it relies on platform constructors");
+            #[used]
+            #[cfg_attr(any(target_os = "linux", target_os = "android", target_os = "freebsd", target_os = "netbsd", target_os = "openbsd", target_os = "dragonfly", target_os = "illumos"), link_section = ".init_array")]
+            #[cfg_attr(any(target_os = "macos", target_os = "ios"), link_section = "__DATA,__mod_init_func")]
+            #[cfg_attr(windows, link_section = ".CRT$XCU")]
+            static SUBMIT: extern "C" fn() = {
+                extern "C" fn submit() {
+                    SUBMISSION.submit();
+                }
+                submit
+            };
+        };
+    })
+}
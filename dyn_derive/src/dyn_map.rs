@@ -0,0 +1,236 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+
+use crate::errors::Errors;
+
+struct Params {
+    types: Vec<Param>,
+    consts: Vec<syn::Ident>,
+    error: syn::Ident,
+    errors: Errors,
+}
+
+struct Param {
+    ident: syn::Ident,
+    input: syn::Ident,
+    func: syn::Ident,
+}
+
+fn contains(tokens: TokenStream, params: &Params) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => params.types.iter().any(|param| param.ident == ident),
+        TokenTree::Group(group) => contains(group.stream(), params),
+        _ => false,
+    })
+}
+
+fn find<'i>(ty: &syn::Type, params: &'i Params) -> Option<&'i Param> {
+    let syn::Type::Path(tp) = ty else {
+        return None
+    };
+    params.types.iter().find(|param| tp.qself.is_none() && tp.path.is_ident(&param.ident))
+}
+
+fn map_arg(ty: &syn::Type, params: &Params, is_try: bool) -> TokenStream {
+    if let Some(Param { func, .. }) = find(ty, params) {
+        return quote! { #func }
+    }
+    let x = quote! { x };
+    match map_expr(ty, &x, params, is_try) {
+        (expr, true) => quote! { &mut |x| #expr },
+        (expr, false) if is_try => {
+            let error = &params.error;
+            quote! { &mut |x| Ok::<_, #error>(#expr) }
+        },
+        (expr, false) => quote! { &mut |x| #expr },
+    }
+}
+
+fn map_field(ty: &syn::Type, expr: &impl ToTokens, params: &Params, is_try: bool) -> TokenStream {
+    match map_expr(ty, expr, params, is_try) {
+        (expr, true) => quote! { #expr? },
+        (expr, false) => expr,
+    }
+}
+
+/// Returns the mapped expression, and whether it is a `Result` to be propagated.
+fn map_expr(ty: &syn::Type, expr: &impl ToTokens, params: &Params, is_try: bool) -> (TokenStream, bool) {
+    if !contains(ty.to_token_stream(), params) {
+        return (expr.to_token_stream(), false)
+    }
+    if let Some(Param { func, .. }) = find(ty, params) {
+        return (quote! { #func(#expr) }, is_try)
+    }
+    let method = match is_try {
+        true => quote! { try_map },
+        false => quote! { map },
+    };
+    match ty {
+        syn::Type::Paren(paren) => map_expr(&paren.elem, expr, params, is_try),
+        syn::Type::Group(group) => map_expr(&group.elem, expr, params, is_try),
+        syn::Type::Tuple(tuple) => {
+            let idents = (0..tuple.elems.len()).map(|index| format_ident!("x{}", index + 1)).collect::<Vec<_>>();
+            let exprs = tuple.elems.iter().zip(&idents).map(|(ty, ident)| map_field(ty, ident, params, is_try));
+            (quote! {{
+                let (#(#idents,)*) = #expr;
+                (#(#exprs,)*)
+            }}, false)
+        },
+        syn::Type::Array(array) => {
+            let arg = map_arg(&array.elem, params, is_try);
+            (quote! { ::dyn_std::map::Map1::#method(#expr, #arg) }, is_try)
+        },
+        syn::Type::Path(tp) if tp.qself.is_none() => {
+            let last = tp.path.segments.last().unwrap();
+            let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+                params.errors.push(ty, "type parameters can only be mapped in tuples, arrays and type arguments");
+                return (expr.to_token_stream(), false)
+            };
+            let tys = args.args.iter().filter_map(|arg| match arg {
+                // const parameters are parsed as types, and other const arguments are literals or blocks
+                syn::GenericArgument::Type(syn::Type::Path(tp)) if params.consts.iter().any(|ident| tp.path.is_ident(ident)) => None,
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            }).collect::<Vec<_>>();
+            if last.ident == "Box" && tys.len() == 1 {
+                if let syn::Type::Slice(slice) = tys[0] {
+                    let arg = map_arg(&slice.elem, params, is_try);
+                    return (quote! { ::dyn_std::map::Map1::#method(#expr, #arg) }, is_try)
+                }
+                let expr = map_field(tys[0], &quote! { *#expr }, params, is_try);
+                return (quote! { Box::new(#expr) }, false)
+            }
+            let args = tys.iter().map(|ty| map_arg(ty, params, is_try));
+            let ident = format_ident!("Map{}", tys.len());
+            (quote! { ::dyn_std::map::#ident::#method(#expr, #(#args),*) }, is_try)
+        },
+        _ => {
+            params.errors.push(ty, "type parameters can only be mapped in tuples, arrays and type arguments");
+            (expr.to_token_stream(), false)
+        },
+    }
+}
+
+fn map_fields(fields: &syn::Fields, path: &TokenStream, params: &Params, is_try: bool) -> (TokenStream, TokenStream) {
+    match fields {
+        syn::Fields::Named(named) => {
+            let (pats, exprs) = named.named.iter().map(|field| {
+                let ident = field.ident.as_ref().unwrap();
+                let expr = map_field(&field.ty, ident, params, is_try);
+                if ident == &expr.to_string() {
+                    return (quote! { #ident }, quote! { #ident })
+                }
+                (quote! { #ident }, quote! { #ident: #expr })
+            }).unzip::<_, _, Vec<_>, Vec<_>>();
+            (quote! { #path { #(#pats),* } }, quote! { #path { #(#exprs),* } })
+        },
+        syn::Fields::Unnamed(unnamed) => {
+            let (pats, exprs) = unnamed.unnamed.iter().enumerate().map(|(index, field)| {
+                let ident = format_ident!("a{}", index + 1);
+                let expr = map_field(&field.ty, &ident, params, is_try);
+                (quote! { #ident }, expr)
+            }).unzip::<_, _, Vec<_>, Vec<_>>();
+            (quote! { #path(#(#pats),*) }, quote! { #path(#(#exprs),*) })
+        },
+        syn::Fields::Unit => (quote! { #path }, quote! { #path }),
+    }
+}
+
+pub fn derive(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let errors = Errors::default();
+    let types = input.generics.type_params().enumerate().map(|(index, param)| {
+        if !param.bounds.is_empty() {
+            errors.push(&param.bounds, "bounds on mapped type parameters are not supported");
+        }
+        Param {
+            ident: param.ident.clone(),
+            input: format_ident!("S{}", index + 1),
+            func: format_ident!("f{}", index + 1),
+        }
+    }).collect::<Vec<_>>();
+    let consts = input.generics.const_params().map(|param| param.ident.clone()).collect();
+    // the error type of `try_map` should not shadow the type parameters
+    let error = (0usize..).map(|index| match index {
+        0 => format_ident!("E"),
+        _ => format_ident!("E{}", index),
+    }).find(|error| input.generics.params.iter().all(|param| match param {
+        syn::GenericParam::Type(param) => &param.ident != error,
+        syn::GenericParam::Const(param) => &param.ident != error,
+        syn::GenericParam::Lifetime(_) => true,
+    })).unwrap();
+    let params = Params { types, consts, error, errors };
+    if params.types.is_empty() {
+        return Err(syn::Error::new_spanned(ident, "`DynMap` requires at least one type parameter"))
+    }
+    if params.types.len() > ::dyn_std::map::MAX_ARITY {
+        let message = format!("`DynMap` supports at most {} type parameters", ::dyn_std::map::MAX_ARITY);
+        params.errors.push(&input.generics.params, message);
+    }
+    if let Some(where_clause) = &input.generics.where_clause {
+        for predicate in &where_clause.predicates {
+            if contains(predicate.to_token_stream(), &params) {
+                params.errors.push(predicate, "bounds on mapped type parameters are not supported");
+            }
+        }
+    }
+    if let syn::Data::Union(data) = &input.data {
+        params.errors.push(data.union_token, "`DynMap` cannot be derived for unions");
+    }
+    let arms = |is_try: bool| -> Vec<TokenStream> {
+        let wrap = |expr: TokenStream| match is_try {
+            true => quote! { Ok(#expr) },
+            false => expr,
+        };
+        match &input.data {
+            syn::Data::Struct(data) => {
+                let (pat, expr) = map_fields(&data.fields, &quote! { #ident }, &params, is_try);
+                let expr = wrap(expr);
+                vec![quote! { #pat => #expr }]
+            },
+            syn::Data::Enum(data) => data.variants.iter().map(|variant| {
+                let name = &variant.ident;
+                let (pat, expr) = map_fields(&variant.fields, &quote! { #ident::#name }, &params, is_try);
+                let expr = wrap(expr);
+                quote! { #pat => #expr }
+            }).collect(),
+            syn::Data::Union(_) => vec![],
+        }
+    };
+    let map_arms = arms(false);
+    // errors in fields are reported once, not again for `try_map`
+    params.errors.check()?;
+    let try_map_arms = arms(true);
+    let input_args = input.generics.params.iter().map(|param| match param {
+        syn::GenericParam::Type(param) => {
+            let param = params.types.iter().find(|p| p.ident == param.ident).unwrap();
+            param.input.to_token_stream()
+        },
+        syn::GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+        syn::GenericParam::Const(param) => param.ident.to_token_stream(),
+    });
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let trait_ident = format_ident!("Map{}", params.types.len());
+    let targets = params.types.iter().map(|param| &param.ident).collect::<Vec<_>>();
+    let inputs = params.types.iter().map(|param| &param.input).collect::<Vec<_>>();
+    let funcs = params.types.iter().map(|param| &param.func).collect::<Vec<_>>();
+    let error = &params.error;
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::dyn_std::map::#trait_ident<#(#targets),*> for #ident #type_generics #where_clause {
+            type Input<#(#inputs),*> = #ident<#(#input_args),*>;
+            #[inline]
+            fn map<#(#inputs),*>(value: Self::Input<#(#inputs),*>, #(#funcs: &mut impl FnMut(#inputs) -> #targets),*) -> Self {
+                match value {
+                    #(#map_arms,)*
+                }
+            }
+            #[inline]
+            fn try_map<#(#inputs,)* #error>(value: Self::Input<#(#inputs),*>, #(#funcs: &mut impl FnMut(#inputs) -> Result<#targets, #error>),*) -> Result<Self, #error> {
+                match value {
+                    #(#try_map_arms,)*
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,34 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn derive(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    let mut name = None;
+    for attr in &input.attrs {
+        if !attr.path().is_ident("dyn_name") {
+            continue;
+        }
+        let syn::Meta::NameValue(meta) = &attr.meta else {
+            return Err(syn::Error::new_spanned(attr, "expected `#[dyn_name = \"..\"]`"))
+        };
+        let syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(lit), .. }) = &meta.value else {
+            return Err(syn::Error::new_spanned(&meta.value, "expected a string literal"))
+        };
+        if name.is_some() {
+            return Err(syn::Error::new_spanned(attr, "duplicate `dyn_name` attribute"))
+        }
+        name = Some(lit.clone());
+    }
+    let Some(name) = name else {
+        return Err(syn::Error::new_spanned(&input.ident, "`DynName` requires a `#[dyn_name = \"..\"]` attribute"))
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&input.generics, "`DynName` cannot be derived for generic types, whose instantiations would share a name"))
+    }
+    let ident = &input.ident;
+    Ok(quote! {
+        #[automatically_derived]
+        impl ::dyn_std::DynName for #ident {
+            const NAME: &'static str = #name;
+        }
+    })
+}
@@ -1,19 +1,229 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 
+use crate::args::Args;
+use crate::errors::Errors;
 use crate::generics::GenericsData;
 use crate::subst::Context;
+use crate::subst_self::subst_self;
 
-fn supertraits(fact: &mut syn::ItemTrait, inst: &mut syn::ItemTrait, cons: &mut syn::ItemTrait) -> TokenStream {
+const BINARY_OPS: [&str; 10] = ["Add", "Sub", "Mul", "Div", "Rem", "BitAnd", "BitOr", "BitXor", "Shl", "Shr"];
+
+fn is_self(ty: &syn::Type) -> bool {
+    matches!(ty, syn::Type::Path(tp) if tp.qself.is_none() && tp.path.is_ident("Self"))
+}
+
+fn is_ref_self(ty: &syn::Type) -> bool {
+    matches!(ty, syn::Type::Reference(reference) if reference.mutability.is_none() && is_self(&reference.elem))
+}
+
+/// Returns the name of operator bounds on references, such as `AddAssign<&'a Self>` or `Add<&'a Self, Output = Self>`.
+fn ref_op(path: &syn::Path, has_output: bool) -> Option<String> {
+    let last = path.segments.last()?;
+    let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+        return None
+    };
+    let mut args = args.args.iter();
+    match args.next() {
+        Some(syn::GenericArgument::Type(ty)) if is_ref_self(ty) => {},
+        _ => return None,
+    }
+    match (args.next(), has_output) {
+        (None, false) => {},
+        (Some(syn::GenericArgument::AssocType(assoc)), true) if assoc.ident == "Output" && is_self(&assoc.ty) => {},
+        _ => return None,
+    }
+    if args.next().is_some() {
+        return None
+    }
+    Some(last.ident.to_string())
+}
+
+/// Takes out where predicates of binary operators on references, such as `for<'a> &'a Self: Add<&'a Self, Output = Self>`.
+fn take_ref_ops(generics: &mut syn::Generics) -> Vec<(String, syn::PredicateType)> {
+    let Some(where_clause) = &mut generics.where_clause else {
+        return vec![]
+    };
+    let mut ops = vec![];
+    where_clause.predicates = std::mem::take(&mut where_clause.predicates).into_iter().filter(|predicate| {
+        let syn::WherePredicate::Type(predicate) = predicate else {
+            return true
+        };
+        let (1, Some(syn::TypeParamBound::Trait(bound))) = (predicate.bounds.len(), predicate.bounds.first()) else {
+            return true
+        };
+        if !is_ref_self(&predicate.bounded_ty) {
+            return true
+        }
+        match ref_op(&bound.path, true) {
+            Some(op) if BINARY_OPS.contains(&op.as_str()) => {
+                ops.push((op, predicate.clone()));
+                false
+            },
+            _ => true,
+        }
+    }).collect();
+    if where_clause.predicates.is_empty() {
+        generics.where_clause = None;
+    }
+    ops
+}
+
+/// Rewrites a where predicate of the original trait for the implementation `Factory`.
+fn factory_predicate(mut predicate: syn::PredicateType) -> syn::WherePredicate {
+    let factory = syn::parse_quote! { Factory };
+    subst_self(&mut predicate.bounded_ty, &factory);
+    for bound in &mut predicate.bounds {
+        let syn::TypeParamBound::Trait(bound) = bound else {
+            continue;
+        };
+        let syn::PathArguments::AngleBracketed(args) = &mut bound.path.segments.last_mut().unwrap().arguments else {
+            continue;
+        };
+        for arg in &mut args.args {
+            match arg {
+                syn::GenericArgument::Type(ty) => subst_self(ty, &factory),
+                syn::GenericArgument::AssocType(assoc) => subst_self(&mut assoc.ty, &factory),
+                _ => {},
+            }
+        }
+    }
+    syn::WherePredicate::Type(predicate)
+}
+
+/// Recognizes `serde::Serialize` and `serde::Deserialize` by their full paths, such as `serde::de::DeserializeOwned`.
+fn serde_trait(path: &syn::Path) -> Option<&'static str> {
+    let segments = path.segments.iter().map(|segment| segment.ident.to_string()).collect::<Vec<_>>();
+    let segments = segments.iter().map(String::as_str).collect::<Vec<_>>();
+    match segments[..] {
+        ["serde", "Serialize"] | ["serde", "ser", "Serialize"] => Some("Serialize"),
+        ["serde", "Deserialize"] | ["serde", "de", "Deserialize"] | ["serde", "de", "DeserializeOwned"] => Some("Deserialize"),
+        _ => None,
+    }
+}
+
+/// Rejects serde traits imported by `use`, which would otherwise be taken as other traits.
+fn check_serde_imports(fact: &syn::ItemTrait, errors: &Errors) {
+    for bound in &fact.supertraits {
+        let syn::TypeParamBound::Trait(bound) = bound else {
+            continue;
+        };
+        let Some(ident) = bound.path.get_ident() else {
+            continue;
+        };
+        let path = match ident.to_string().as_str() {
+            "Serialize" => "serde::Serialize",
+            "Deserialize" => "serde::Deserialize",
+            "DeserializeOwned" => "serde::de::DeserializeOwned",
+            _ => continue,
+        };
+        let message = format!("serde traits are recognized by their full paths, write `{}` instead, or `self::{}` for another trait", path, ident);
+        errors.push(&bound.path, message);
+    }
+}
+
+fn has_bound(item: &syn::ItemTrait, name: &str) -> bool {
+    item.supertraits.iter().any(|bound| matches!(bound, syn::TypeParamBound::Trait(bound) if bound.path.is_ident(name)))
+}
+
+fn supertraits(fact: &mut syn::ItemTrait, inst: &mut syn::ItemTrait, cons: &mut syn::ItemTrait, args: &Args, ref_ops: &[(String, syn::PredicateType)]) -> TokenStream {
     let mut has_sized = false;
+    let mut has_serialize = false;
+    let mut has_deserialize = false;
+    let cons_ident = &cons.ident;
+    let tag = match &args.tag {
+        Some(tag) => quote! { Some(#tag) },
+        None => quote! { None },
+    };
     let inst_ident = &inst.ident;
     let (impl_generics, type_generics, where_clause) = inst.generics.split_for_impl();
     let mut output = quote! {};
+    let mut ref_bounds: Vec<syn::TypeParamBound> = vec![];
+    for (op, _) in ref_ops {
+        let name = format_ident!("{}", op);
+        let ref_name = format_ident!("{}Ref", op);
+        let method = format_ident!("{}", op.to_lowercase());
+        let dyn_method = format_ident!("dyn_{}_ref", method);
+        ref_bounds.push(syn::parse_quote! { ::dyn_std::ops::#ref_name });
+        output.extend(quote! {
+            #[automatically_derived]
+            impl #impl_generics std::ops::#name for &Box<dyn #inst_ident #type_generics> #where_clause {
+                type Output = Box<dyn #inst_ident #type_generics>;
+                #[inline]
+                fn #method(self, other: Self) -> Self::Output {
+                    ::dyn_std::Fat::to_box(self, |m| m.#dyn_method((**other).as_dyn()))
+                }
+            }
+        });
+    }
     inst.supertraits = syn::punctuated::Punctuated::from_iter(fact.supertraits.iter_mut().flat_map(|param| {
         let syn::TypeParamBound::Trait(fact_bound) = param else {
             return Some(param.clone())
         };
         let mut inst_bound = fact_bound.clone();
+        if let Some(op) = ref_op(&fact_bound.path, false).filter(|op| op.ends_with("Assign") && BINARY_OPS.contains(&&op[0..op.len() - 6])) {
+            let name = format_ident!("{}", op);
+            let ref_name = format_ident!("{}Ref", op);
+            let method = format_ident!("{}_assign", op[0..op.len() - 6].to_lowercase());
+            let dyn_method = format_ident!("dyn_{}_ref", method);
+            inst_bound.lifetimes = None;
+            inst_bound.path = syn::parse_quote! { ::dyn_std::ops::#ref_name };
+            output.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics std::ops::#name<&Self> for Box<dyn #inst_ident #type_generics> #where_clause {
+                    #[inline]
+                    fn #method(&mut self, other: &Self) {
+                        (**self).#dyn_method((**other).as_dyn())
+                    }
+                }
+            });
+            output.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics std::ops::#name<&Box<dyn #inst_ident #type_generics>> for dyn #inst_ident #type_generics #where_clause {
+                    #[inline]
+                    fn #method(&mut self, other: &Box<dyn #inst_ident #type_generics>) {
+                        self.#dyn_method((**other).as_dyn())
+                    }
+                }
+            });
+            return Some(syn::TypeParamBound::Trait(inst_bound))
+        }
+        match serde_trait(&fact_bound.path) {
+            Some("Serialize") => {
+                has_serialize = true;
+                inst_bound.path = syn::parse_quote! { ::dyn_std::serde::Serialize };
+                output.extend(quote! {
+                    #[automatically_derived]
+                    impl #impl_generics ::dyn_std::serde::serde::Serialize for dyn #inst_ident #type_generics #where_clause {
+                        #[inline]
+                        fn serialize<S: ::dyn_std::serde::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                            ::dyn_std::serde::serialize(self, #tag, serializer)
+                        }
+                    }
+                });
+                return Some(syn::TypeParamBound::Trait(inst_bound))
+            },
+            Some(_) => {
+                // constructors deserialize instances, which are looked up in the global registry,
+                // or otherwise in any registry passed to `dyn_std::serde::deserialize`
+                has_deserialize = true;
+                if args.registry {
+                    output.extend(quote! {
+                        #[automatically_derived]
+                        impl<'de> ::dyn_std::serde::serde::Deserialize<'de> for Box<dyn #inst_ident #type_generics> {
+                            #[inline]
+                            fn deserialize<D: ::dyn_std::serde::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                                let registry = ::dyn_std::registry::Registry::<dyn #cons_ident + Send + Sync>::global()
+                                    .map_err(<D::Error as ::dyn_std::serde::serde::de::Error>::custom)?;
+                                ::dyn_std::serde::deserialize(|name| Some(registry.read().get(name)?.as_static()), #tag, deserializer)
+                            }
+                        }
+                    });
+                }
+                return None
+            },
+            _ => {},
+        }
         let op = inst_bound.path.to_token_stream().to_string();
         match op.as_str() {
             "Sized" => {
@@ -60,6 +270,29 @@ fn supertraits(fact: &mut syn::ItemTrait, inst: &mut syn::ItemTrait, cons: &mut
                         }
                     }
                 });
+                let pointers = [
+                    quote! { &dyn #inst_ident #type_generics },
+                    quote! { Box<dyn #inst_ident #type_generics> },
+                    quote! { std::rc::Rc<dyn #inst_ident #type_generics> },
+                    quote! { std::sync::Arc<dyn #inst_ident #type_generics> },
+                ];
+                for (i, lhs) in pointers.iter().enumerate() {
+                    for (j, rhs) in pointers.iter().enumerate() {
+                        // `Rc` and `Arc` cannot be compared with each other due to the orphan rule
+                        if i == j || i >= 2 && j >= 2 {
+                            continue;
+                        }
+                        output.extend(quote! {
+                            #[automatically_derived]
+                            impl #impl_generics std::cmp::#name<#rhs> for #lhs #where_clause {
+                                #[inline]
+                                fn #method(&self, other: &#rhs) -> #return_type {
+                                    (**self).#dyn_method((**other).as_any())
+                                }
+                            }
+                        });
+                    }
+                }
             },
             "Neg" | "Not" => {
                 let name = format_ident!("{}", op);
@@ -100,7 +333,7 @@ fn supertraits(fact: &mut syn::ItemTrait, inst: &mut syn::ItemTrait, cons: &mut
             "BitAndAssign" | "BitOrAssign" | "BitXorAssign" | "ShlAssign" | "ShrAssign" => {
                 let name = format_ident!("{}", op);
                 let method = format_ident!("{}_assign", op[0..op.len() - 6].to_lowercase());
-                let dyn_method = format_ident!("dyn_{}_assign", method);
+                let dyn_method = format_ident!("dyn_{}", method);
                 inst_bound.path = syn::parse_quote! { ::dyn_std::ops::#name };
                 output.extend(quote! {
                     #[automatically_derived]
@@ -111,6 +344,16 @@ fn supertraits(fact: &mut syn::ItemTrait, inst: &mut syn::ItemTrait, cons: &mut
                         }
                     }
                 });
+                // so that `*target += other` works for `target: &mut dyn Instance`
+                output.extend(quote! {
+                    #[automatically_derived]
+                    impl #impl_generics std::ops::#name<Box<dyn #inst_ident #type_generics>> for dyn #inst_ident #type_generics #where_clause {
+                        #[inline]
+                        fn #method(&mut self, other: Box<dyn #inst_ident #type_generics>) {
+                            self.#dyn_method(other.as_any_box())
+                        }
+                    }
+                });
             },
             _ => {},
         }
@@ -119,9 +362,27 @@ fn supertraits(fact: &mut syn::ItemTrait, inst: &mut syn::ItemTrait, cons: &mut
     if !has_sized {
         fact.supertraits.push(syn::parse_quote! { Sized });
     }
+    if has_serialize {
+        fact.supertraits.push(syn::parse_quote! { ::dyn_std::DynName });
+    }
     fact.supertraits.push(syn::parse_quote! { 'static });
-    inst.supertraits.push(syn::parse_quote! { ::dyn_std::any::Dyn });
+    inst.supertraits.extend(ref_bounds);
     cons.supertraits = Default::default();
+    if has_deserialize && !args.constructor_only {
+        cons.supertraits.push(syn::parse_quote! { ::dyn_std::serde::Deserialize<dyn #inst_ident #type_generics> });
+    }
+    for ident in &args.auto_traits {
+        if !has_bound(inst, &ident.to_string()) {
+            inst.supertraits.push(syn::parse_quote! { #ident });
+        }
+        cons.supertraits.push(syn::parse_quote! { #ident });
+    }
+    // boxed `dyn Any` keeps the auto traits of the instance trait
+    inst.supertraits.push(match (has_bound(inst, "Send"), has_bound(inst, "Sync")) {
+        (true, true) => syn::parse_quote! { ::dyn_std::any::DynSendSync },
+        (true, false) => syn::parse_quote! { ::dyn_std::any::DynSend },
+        (false, _) => syn::parse_quote! { ::dyn_std::any::Dyn },
+    });
     output
 }
 
@@ -140,8 +401,9 @@ pub fn get_full_name(item: &syn::ItemTrait) -> (TokenStream, TokenStream) {
                 let lifetime = &param.lifetime;
                 generic_params.push(quote! { #lifetime });
             },
-            syn::GenericParam::Const(_) => {
-                unimplemented!("const generics in traits")
+            syn::GenericParam::Const(param) => {
+                let ident = &param.ident;
+                generic_params.push(quote! { #ident });
             },
         }
     }
@@ -157,16 +419,244 @@ pub fn get_full_name(item: &syn::ItemTrait) -> (TokenStream, TokenStream) {
     )
 }
 
-pub fn transform(_attr: TokenStream, mut fact: syn::ItemTrait) -> TokenStream {
+fn alias(ident: &syn::Ident, vis: &syn::Visibility, inst: &syn::ItemTrait) -> TokenStream {
+    let (inst_trait, _) = get_full_name(inst);
+    let params = inst.generics.params.iter().map(|param| match param {
+        syn::GenericParam::Type(param) => param.ident.to_token_stream(),
+        syn::GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+        syn::GenericParam::Const(param) => {
+            let ident = &param.ident;
+            let ty = &param.ty;
+            quote! { const #ident: #ty }
+        },
+    }).collect::<Vec<_>>();
+    let generics = match params.len() {
+        0 => quote! {},
+        _ => quote! { <#(#params),*> },
+    };
+    quote! {
+        #vis type #ident #generics = Box<dyn #inst_trait>;
+    }
+}
+
+/// Returns how a method returns early on mismatched implementations, if its return type is fallible.
+///
+/// Only `Result<T, E>` and `Option<T>` of the prelude (or by their full paths in `std` or `core`) are fallible,
+/// so that aliases such as `fmt::Result` or `io::Result<T>` are not mistaken for them.
+fn mismatch_suffix(output: &syn::ReturnType) -> Option<TokenStream> {
+    let syn::ReturnType::Type(_, ty) = output else {
+        return None
+    };
+    let syn::Type::Path(tp) = ty.as_ref() else {
+        return None
+    };
+    if tp.qself.is_some() {
+        return None
+    }
+    let last = tp.path.segments.last()?;
+    let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+        return None
+    };
+    let segments = tp.path.segments.iter().map(|segment| segment.ident.to_string()).collect::<Vec<_>>();
+    let segments = segments.iter().map(String::as_str).collect::<Vec<_>>();
+    let module = match segments[..] {
+        [_] if tp.path.leading_colon.is_none() => None,
+        ["std" | "core", module, _] => Some(module),
+        _ => return None,
+    };
+    match (module, segments[segments.len() - 1], args.args.len()) {
+        // the error type is converted from `Mismatch` by `?`
+        (None | Some("result"), "Result", 2) => Some(quote! { ? }),
+        (None | Some("option"), "Option", 1) => Some(quote! { .ok()? }),
+        _ => None,
+    }
+}
+
+/// Generates `Downcast` and inherent methods of `dyn {Instance}`, also with `Send` and `Sync`.
+fn inherent(inst: &syn::ItemTrait, cons_trait: Option<&TokenStream>, generics: &syn::Generics) -> TokenStream {
+    let inst_ident = &inst.ident;
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let impls = [quote! {}, quote! { + Send }, quote! { + Send + Sync }].map(|auto_traits| {
+        let self_ty = quote! { dyn #inst_ident #type_generics #auto_traits };
+        let constructor = cons_trait.map(|cons_trait| quote! {
+            impl #impl_generics #self_ty #where_clause {
+                /// Returns the constructor of the implementation, or `None` if it is not an `Instance<T>`.
+                #[inline]
+                pub fn constructor(&self) -> Option<&'static dyn #cons_trait> {
+                    self.dyn_constructor()
+                }
+            }
+        });
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::dyn_std::Downcast for #self_ty #where_clause {
+                #[inline]
+                fn type_name_of_inner(&self) -> &'static str {
+                    self.dyn_type_name_of_inner()
+                }
+                #[inline]
+                fn type_id_of_inner(&self) -> ::std::any::TypeId {
+                    self.dyn_type_id_of_inner()
+                }
+            }
+            #constructor
+        }
+    });
+    quote! { #(#impls)* }
+}
+
+/// Generates `Debug`, `PartialEq`, `Eq` and `Hash` of `dyn {Constructor}`, keyed by the implementation.
+fn identity(cons_trait: &TokenStream, generics: &syn::Generics) -> TokenStream {
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    // constructors are also stored as `dyn {Constructor} + Send + Sync` in registries
+    let impls = [quote! { dyn #cons_trait }, quote! { dyn #cons_trait + Send + Sync }].map(|self_ty| quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::fmt::Debug for #self_ty #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, "Constructor<{}>", self.dyn_type_name_of_inner())
+            }
+        }
+        #[automatically_derived]
+        impl #impl_generics ::core::cmp::PartialEq for #self_ty #where_clause {
+            fn eq(&self, other: &Self) -> bool {
+                self.dyn_type_id_of_inner() == other.dyn_type_id_of_inner()
+            }
+        }
+        #[automatically_derived]
+        impl #impl_generics ::core::cmp::Eq for #self_ty #where_clause {}
+        #[automatically_derived]
+        impl #impl_generics ::core::hash::Hash for #self_ty #where_clause {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                self.dyn_type_id_of_inner().hash(state)
+            }
+        }
+    });
+    quote! { #(#impls)* }
+}
+
+/// Generates inherent `wrap` and `wrap_ref` of `dyn {Constructor}`, also with `Send` and `Sync`,
+/// unless they would shadow functions of the constructor trait.
+fn wrap(items: &[syn::TraitItem], inst_trait: &TokenStream, cons_trait: &TokenStream, generics: &syn::Generics) -> TokenStream {
+    let shadows = |name: &str| items.iter().any(|item| {
+        matches!(item, syn::TraitItem::Fn(item) if item.sig.ident == name && item.sig.receiver().is_none())
+    });
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let impls = [quote! { dyn #cons_trait }, quote! { dyn #cons_trait + Send + Sync }].map(|self_ty| {
+        let wrap = (!shadows("wrap")).then(|| quote! {
+            /// Wraps a value of the implementation into an instance, or gives it back if it is of another type.
+            #[inline]
+            pub fn wrap(&self, value: Box<dyn ::std::any::Any>) -> Result<Box<dyn #inst_trait>, Box<dyn ::std::any::Any>> {
+                self.dyn_wrap(value)
+            }
+        });
+        let wrap_ref = (!shadows("wrap_ref")).then(|| quote! {
+            /// Wraps a reference to a value of the implementation into an instance, or returns `None` if it is of another type.
+            #[inline]
+            pub fn wrap_ref<'value>(&self, value: &'value dyn ::std::any::Any) -> Option<&'value dyn #inst_trait> {
+                self.dyn_wrap_ref(value)
+            }
+        });
+        quote! {
+            impl #impl_generics #self_ty #where_clause {
+                #wrap
+                #wrap_ref
+            }
+        }
+    });
+    quote! { #(#impls)* }
+}
+
+/// Generates `Construct<Factory>` of `dyn {Constructor}`, also with `Send` and `Sync`, for registries.
+fn construct(cons_trait: &TokenStream, generics: &syn::Generics) -> TokenStream {
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let impls = [quote! { dyn #cons_trait }, quote! { dyn #cons_trait + Send + Sync }].map(|self_ty| quote! {
+        #[automatically_derived]
+        impl #impl_generics ::dyn_std::registry::Construct<Factory> for #self_ty #where_clause {
+            #[inline]
+            fn construct() -> Box<Self> {
+                Box::new(::dyn_std::Constructor::<Factory>::new())
+            }
+        }
+    });
+    quote! { #(#impls)* }
+}
+
+fn snake_case(ident: &syn::Ident) -> String {
+    let mut output = String::new();
+    for (index, char) in ident.to_string().chars().enumerate() {
+        if char.is_uppercase() {
+            if index > 0 {
+                output.push('_');
+            }
+            output.extend(char.to_lowercase());
+        } else {
+            output.push(char);
+        }
+    }
+    output
+}
+
+/// Generates the `{Name}Registry` alias, the global registry and the `register_{name}` helper.
+fn registry(fact: &syn::ItemTrait, cons: &syn::ItemTrait) -> TokenStream {
+    let vis = &cons.vis;
+    let fact_ident = &fact.ident;
+    let cons_ident = &cons.ident;
+    let ident = format_ident!("{}Registry", fact_ident);
+    let register = format_ident!("register_{}", snake_case(fact_ident));
+    let doc = format!(" Registers the constructor of `T` in the global [`{}`].", ident);
+    quote! {
+        #vis type #ident = ::dyn_std::registry::Registry<dyn #cons_ident + Send + Sync>;
+        #[automatically_derived]
+        impl ::dyn_std::registry::Global for dyn #cons_ident + Send + Sync {
+            fn global() -> Result<&'static ::dyn_std::registry::SyncRegistry<Self>, ::dyn_std::registry::Error> {
+                static REGISTRY: ::std::sync::OnceLock<Result<::dyn_std::registry::SyncRegistry<dyn #cons_ident + Send + Sync>, ::dyn_std::registry::Error>> = ::std::sync::OnceLock::new();
+                REGISTRY.get_or_init(::dyn_std::registry::SyncRegistry::submitted).as_ref().map_err(Clone::clone)
+            }
+            fn submissions() -> &'static ::dyn_std::registry::Submissions<Self> {
+                static SUBMISSIONS: ::dyn_std::registry::Submissions<dyn #cons_ident + Send + Sync> = ::dyn_std::registry::Submissions::new();
+                &SUBMISSIONS
+            }
+        }
+        #[doc = #doc]
+        #vis fn #register<T: #fact_ident>(name: impl Into<String>) -> Result<(), ::dyn_std::registry::Error>
+        where
+            dyn #cons_ident + Send + Sync: ::dyn_std::registry::Construct<T>,
+        {
+            #ident::global()?.register::<T>(name)
+        }
+    }
+}
+
+pub fn transform(attr: TokenStream, mut fact: syn::ItemTrait) -> syn::Result<TokenStream> {
+    let args = Args::parse(attr)?;
+    let errors = Errors::default();
+    for param in fact.generics.const_params() {
+        errors.push(param, "const generics are not supported in traits");
+    }
+    check_serde_imports(&fact, &errors);
     let mut inst = fact.clone();
-    inst.ident = format_ident!("{}Instance", fact.ident);
+    let ref_ops = take_ref_ops(&mut inst.generics);
+    inst.ident = args.instance.clone().unwrap_or_else(|| format_ident!("{}Instance", fact.ident));
     inst.items = Default::default();
+    if let Some(vis) = &args.vis {
+        inst.vis = vis.clone();
+    }
     let (inst_trait, _) = get_full_name(&inst);
     let generics = GenericsData::from(inst_trait.clone(), &mut fact);
     let mut cons = inst.clone();
-    cons.ident = format_ident!("{}Constructor", fact.ident);
+    cons.ident = args.constructor.clone().unwrap_or_else(|| format_ident!("{}Constructor", fact.ident));
     let (cons_trait, _) = get_full_name(&cons);
-    let super_impls = supertraits(&mut fact, &mut inst, &mut cons);
+    let super_impls = supertraits(&mut fact, &mut inst, &mut cons, &args, &ref_ops);
+    if let (true, Some(ident)) = (args.constructor_only, &args.alias) {
+        errors.push(ident, "`alias` requires the instance trait, which is not generated in `constructor_only` mode");
+    }
+    if args.registry && args.instance_only {
+        errors.push(&fact.ident, "`registry` requires the constructor trait, which is not generated in `instance_only` mode");
+    }
+    if args.registry && !fact.generics.params.is_empty() {
+        errors.push(&fact.generics, "`registry` is not supported for generic traits");
+    }
+    let alias = args.alias.as_ref().map(|ident| alias(ident, &inst.vis, &inst));
     for param in fact.generics.params.iter_mut() {
         let syn::GenericParam::Type(param) = param else {
             continue;
@@ -187,13 +677,27 @@ pub fn transform(_attr: TokenStream, mut fact: syn::ItemTrait) -> TokenStream {
     for fact_item in &generics.items {
         match fact_item {
             syn::TraitItem::Fn(item_fn) => {
+                let fact_sig = &item_fn.sig;
                 let mut item_fn = item_fn.clone();
                 let ident = &item_fn.sig.ident;
                 let has_recv = item_fn.sig.receiver().is_some();
+                if has_recv && args.constructor_only {
+                    errors.push(fact_sig, "methods require the instance trait, which is not generated in `constructor_only` mode");
+                    continue;
+                }
+                if !has_recv && args.instance_only {
+                    errors.push(fact_sig, "functions without receivers require the constructor trait, which is not generated in `instance_only` mode");
+                    continue;
+                }
                 if !has_recv {
                     item_fn.sig.inputs.insert(0, syn::parse_quote! { &self });
                 }
-                let ctx = Context::new(&generics);
+                let mut ctx = Context::new(&generics, &errors);
+                if args.on_mismatch_error() {
+                    if let Some(suffix) = mismatch_suffix(&fact_sig.output) {
+                        ctx.on_mismatch(suffix);
+                    }
+                }
                 let inputs = item_fn.sig.inputs.iter_mut().filter_map(|arg| {
                     match arg {
                         syn::FnArg::Typed(arg) => Some(arg.ty.as_mut()),
@@ -205,10 +709,25 @@ pub fn transform(_attr: TokenStream, mut fact: syn::ItemTrait) -> TokenStream {
                         },
                     }
                 });
-                let (expr, stmts, params, _) = ctx.subst_fn(inputs, &mut item_fn.sig.output, &match has_recv {
+                let (expr, stmts, params, has_match) = ctx.subst_fn(inputs, &mut item_fn.sig.output, &match has_recv {
                     true => quote! { self.0.#ident },
                     false => quote! { Factory::#ident },
                 });
+                if has_match && args.constructor_only {
+                    errors.push(fact_sig, "functions using `Self` require the instance trait, which is not generated in `constructor_only` mode");
+                    continue;
+                }
+                if ctx.is_unchecked() {
+                    item_fn.sig.unsafety = Some(Default::default());
+                    if item_fn.attrs.iter().any(|attr| attr.path().is_ident("doc")) {
+                        item_fn.attrs.push(syn::parse_quote! { #[doc = ""] });
+                    }
+                    item_fn.attrs.extend([
+                        syn::parse_quote! { #[doc = " # Safety"] },
+                        syn::parse_quote! { #[doc = ""] },
+                        syn::parse_quote! { #[doc = " Raw pointers to `Self` must point to the same implementation."] },
+                    ]);
+                }
                 let mut impl_fn = syn::ImplItemFn {
                     attrs: vec![syn::parse_quote! { #[inline] }],
                     vis: syn::Visibility::Inherited,
@@ -224,9 +743,9 @@ pub fn transform(_attr: TokenStream, mut fact: syn::ItemTrait) -> TokenStream {
                             syn::FnArg::Receiver(_) => None,
                         }
                     })
-                    .zip(params.into_iter())
+                    .zip(params)
                     .for_each(|(arg, pat)| {
-                        arg.pat = Box::new(syn::parse_quote! { #pat });
+                        *arg.pat = syn::parse_quote! { #pat };
                     });
                 if has_recv {
                     inst.items.push(syn::TraitItem::Fn(item_fn));
@@ -242,21 +761,161 @@ pub fn transform(_attr: TokenStream, mut fact: syn::ItemTrait) -> TokenStream {
             },
         }
     }
+    if !args.constructor_only {
+        // defaults for implementations other than `Instance<T>`
+        inst.items.push(syn::parse_quote! {
+            #[doc(hidden)]
+            fn dyn_type_name_of_inner(&self) -> &'static str {
+                ::dyn_std::Dyn::type_name(self)
+            }
+        });
+        inst.items.push(syn::parse_quote! {
+            #[doc(hidden)]
+            fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId {
+                ::std::any::Any::type_id(self)
+            }
+        });
+        inst_impl_items.push(syn::parse_quote! {
+            #[inline]
+            fn dyn_type_name_of_inner(&self) -> &'static str {
+                ::std::any::type_name::<Factory>()
+            }
+        });
+        inst_impl_items.push(syn::parse_quote! {
+            #[inline]
+            fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId {
+                ::std::any::TypeId::of::<Factory>()
+            }
+        });
+    }
+    if !args.constructor_only && !args.instance_only {
+        inst.items.push(syn::parse_quote! {
+            #[doc(hidden)]
+            fn dyn_constructor(&self) -> Option<&'static dyn #cons_trait> {
+                None
+            }
+        });
+        inst_impl_items.push(syn::parse_quote! {
+            #[inline]
+            fn dyn_constructor(&self) -> Option<&'static dyn #cons_trait> {
+                Some(const { &::dyn_std::Constructor::<Factory>::new() })
+            }
+        });
+        cons.items.push(syn::parse_quote! {
+            /// Wraps a value of the implementation into an instance, or gives it back if it is of another type.
+            fn dyn_wrap(&self, value: Box<dyn ::std::any::Any>) -> Result<Box<dyn #inst_trait>, Box<dyn ::std::any::Any>> {
+                Err(value)
+            }
+        });
+        cons.items.push(syn::parse_quote! {
+            /// Wraps a reference to a value of the implementation into an instance, or returns `None` if it is of another type.
+            fn dyn_wrap_ref<'value>(&self, _value: &'value dyn ::std::any::Any) -> Option<&'value dyn #inst_trait> {
+                None
+            }
+        });
+        cons_impl_items.push(syn::parse_quote! {
+            #[inline]
+            fn dyn_wrap(&self, value: Box<dyn ::std::any::Any>) -> Result<Box<dyn #inst_trait>, Box<dyn ::std::any::Any>> {
+                Ok(Box::new(::dyn_std::Instance::new(*value.downcast::<Factory>()?)))
+            }
+        });
+        cons_impl_items.push(syn::parse_quote! {
+            #[inline]
+            fn dyn_wrap_ref<'value>(&self, value: &'value dyn ::std::any::Any) -> Option<&'value dyn #inst_trait> {
+                Some(::dyn_std::Instance::from_ref(value.downcast_ref::<Factory>()?))
+            }
+        });
+    }
+    if !args.instance_only {
+        // defaults for implementations other than `Constructor<T>`
+        cons.items.push(syn::parse_quote! {
+            #[doc(hidden)]
+            fn dyn_type_name_of_inner(&self) -> &'static str where Self: 'static {
+                ::std::any::type_name::<Self>()
+            }
+        });
+        cons.items.push(syn::parse_quote! {
+            #[doc(hidden)]
+            fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId where Self: 'static {
+                ::std::any::TypeId::of::<Self>()
+            }
+        });
+        cons_impl_items.push(syn::parse_quote! {
+            #[inline]
+            fn dyn_type_name_of_inner(&self) -> &'static str {
+                ::std::any::type_name::<Factory>()
+            }
+        });
+        cons_impl_items.push(syn::parse_quote! {
+            #[inline]
+            fn dyn_type_id_of_inner(&self) -> ::std::any::TypeId {
+                ::std::any::TypeId::of::<Factory>()
+            }
+        });
+    }
     let mut fact_generics = fact.generics.clone();
-    fact_generics.params.push(syn::parse_quote! { Factory: #fact_trait });
+    take_ref_ops(&mut fact_generics);
+    let inherent_impl = inherent(&inst, (!args.instance_only).then_some(&cons_trait), &fact_generics);
+    let identity_impl = (!args.instance_only).then(|| identity(&cons_trait, &fact_generics));
+    let wrap_impl = (!args.instance_only && !args.constructor_only).then(|| wrap(&generics.items, &inst_trait, &cons_trait, &fact_generics));
+    if !ref_ops.is_empty() {
+        // where clauses of the original trait are not implied by `Factory: Trait`
+        let predicates = ref_ops.into_iter().map(|(_, predicate)| factory_predicate(predicate));
+        fact_generics.make_where_clause().predicates.extend(predicates);
+    }
+    let auto_traits = &args.auto_traits;
+    fact_generics.params.push(syn::parse_quote! { Factory: #fact_trait #(+ #auto_traits)* });
     let (impl_generics, _, where_clause) = fact_generics.split_for_impl();
-    quote! {
+    errors.check()?;
+    let construct_impl = construct(&cons_trait, &fact_generics);
+    let registry = args.registry.then(|| registry(&fact, &cons));
+    let cons_impl = quote! {
+        #[automatically_derived]
+        impl #impl_generics #cons_trait for ::dyn_std::Constructor<Factory> #where_clause {
+            #(#cons_impl_items)*
+        }
+        #identity_impl
+        #wrap_impl
+        #construct_impl
+        #registry
+    };
+    if args.constructor_only {
+        return Ok(quote! {
+            #fact
+            #cons
+            #cons_impl
+        })
+    }
+    let (cons, cons_impl) = match args.instance_only {
+        true => (None, None),
+        false => (Some(cons), Some(cons_impl)),
+    };
+    Ok(quote! {
         #fact
         #inst
         #cons
+        #alias
         #super_impls
         #[automatically_derived]
         impl #impl_generics #inst_trait for ::dyn_std::Instance<Factory> #where_clause {
             #(#inst_impl_items)*
         }
+        #cons_impl
+        #inherent_impl
         #[automatically_derived]
-        impl #impl_generics #cons_trait for ::dyn_std::Constructor<Factory> #where_clause {
-            #(#cons_impl_items)*
+        impl #impl_generics ::dyn_std::adapt::Wrap<Factory> for dyn #inst_trait #where_clause {
+            #[inline]
+            fn wrap(value: Factory) -> Box<Self> {
+                Box::new(::dyn_std::Instance::new(value))
+            }
+            #[inline]
+            fn wrap_ref(value: &Factory) -> &Self {
+                ::dyn_std::Instance::from_ref(value)
+            }
+            #[inline]
+            fn wrap_mut(value: &mut Factory) -> &mut Self {
+                ::dyn_std::Instance::from_mut(value)
+            }
         }
-    }
+    })
 }
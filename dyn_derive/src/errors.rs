@@ -0,0 +1,26 @@
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::rc::Rc;
+
+use quote::ToTokens;
+
+/// Compile errors collected in one pass, so that every unsupported case is reported at once.
+#[derive(Default, Clone)]
+pub struct Errors(Rc<RefCell<Vec<syn::Error>>>);
+
+impl Errors {
+    pub fn push(&self, tokens: impl ToTokens, message: impl Display) {
+        self.0.borrow_mut().push(syn::Error::new_spanned(tokens, message));
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    pub fn check(&self) -> syn::Result<()> {
+        self.0.borrow_mut().drain(..).reduce(|mut error, other| {
+            error.combine(other);
+            error
+        }).map_or(Ok(()), Err)
+    }
+}
@@ -15,6 +15,9 @@ pub struct GenericsData {
     pub name: TokenStream,
     pub items: Vec<syn::TraitItem>,
     pub data: HashMap<String, syn::TraitItemType>,
+    // type parameters of the trait, and whether some of them are dynamic
+    pub params: Vec<syn::Ident>,
+    pub dynamic: bool,
 }
 
 impl GenericsData {
@@ -38,17 +41,18 @@ impl GenericsData {
             // todo: multiple bounds
             let mut ty = ty.clone();
             for bound in &mut ty.bounds {
-                match bound {
-                    syn::TypeParamBound::Trait(bound) => {
-                        let last = bound.path.segments.last_mut().unwrap();
-                        last.ident = format_ident!("{}Instance", last.ident);
-                    },
-                    _ => {},
+                if let syn::TypeParamBound::Trait(bound) = bound {
+                    let last = bound.path.segments.last_mut().unwrap();
+                    last.ident = format_ident!("{}Instance", last.ident);
                 }
             }
             data.insert(ty.ident.to_string(), ty);
         }
-        Self { name, items, data }
+        let params = fact.generics.type_params().map(|param| param.ident.clone()).collect();
+        let dynamic = fact.generics.type_params().any(|param| {
+            param.attrs.iter().any(|attr| attr.meta.path().is_ident("dynamic"))
+        });
+        Self { name, items, data, params, dynamic }
     }
 
     pub fn test(&self, path: &syn::TypePath, is_ref: bool) -> Option<(syn::Type, TokenStream)> {
@@ -66,12 +70,10 @@ impl GenericsData {
             ))
         }
         let last = path.path.segments.last().unwrap();
-        let Some(g) = self.data.get(&last.ident.to_string()) else {
-            return None
-        };
+        let g = self.data.get(&last.ident.to_string())?;
         let ident = &g.ident;
         let bounds = &g.bounds;
-        return Some((
+        Some((
             make_dyn(bounds, is_ref),
             quote! { ::dyn_std::Instance::<Factory::#ident> },
         ))
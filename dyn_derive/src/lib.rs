@@ -2,7 +2,13 @@
 
 use proc_macro::TokenStream;
 
+mod args;
+mod dyn_adapter;
+mod dyn_impl;
+mod dyn_map;
+mod dyn_name;
 mod dyn_trait;
+mod errors;
 mod generics;
 mod subst;
 mod subst_self;
@@ -26,8 +32,110 @@ mod subst_self;
 /// ```
 #[proc_macro_attribute]
 pub fn dyn_trait(attr: TokenStream, input: TokenStream) -> TokenStream {
-    let item = syn::parse2(input.into()).expect("expect trait");
-    dyn_trait::transform(attr.into(), item).into()
+    let item = syn::parse_macro_input!(input as syn::ItemTrait);
+    dyn_trait::transform(attr.into(), item).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// This is a procedural macro for forwarding traits parameterized by `Self` to trait objects.
+/// 
+/// ## Example
+/// 
+/// `dyn Visitor<Self>` in a `#[dyn_trait]` trait becomes `dyn Visitor<dyn MetaInstance>`:
+/// 
+/// ```
+/// use dyn_derive::*;
+/// 
+/// #[dyn_adapter]
+/// pub trait Visitor<#[dynamic] T> {
+///     fn visit(&mut self, node: &T);
+/// }
+/// 
+/// #[dyn_trait]
+/// pub trait Meta {
+///     fn accept(&self, visitor: &mut dyn Visitor<Self>);
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn dyn_adapter(attr: TokenStream, input: TokenStream) -> TokenStream {
+    let item = syn::parse_macro_input!(input as syn::ItemTrait);
+    dyn_adapter::transform(attr.into(), item).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// This is a procedural macro for registering implementations in the global registry of a trait.
+/// 
+/// The name can be omitted if the implementation derives `DynName`.
+/// 
+/// ## Example
+/// 
+/// `Circle` is collected into `ShapeRegistry::global()` without being registered in `main`:
+/// 
+/// ```
+/// use dyn_derive::*;
+/// 
+/// #[dyn_trait(registry)]
+/// pub trait Shape {
+///     fn unit() -> Self;
+/// }
+/// 
+/// pub struct Circle;
+/// 
+/// #[dyn_impl(name = "circle")]
+/// impl Shape for Circle {
+///     fn unit() -> Self {
+///         Circle
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn dyn_impl(attr: TokenStream, input: TokenStream) -> TokenStream {
+    let item = syn::parse_macro_input!(input as syn::ItemImpl);
+    dyn_impl::transform(attr.into(), item).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// This is a derive macro for mapping the type parameters of containers in trait methods.
+/// 
+/// ## Example
+/// 
+/// `Tree<Self>` can be used in a `#[dyn_trait]` trait once `Tree` derives `DynMap`:
+/// 
+/// ```
+/// use dyn_derive::*;
+/// 
+/// #[derive(DynMap)]
+/// pub enum Tree<T> {
+///     Leaf(T),
+///     Node(Box<Tree<T>>, Box<Tree<T>>),
+/// }
+/// 
+/// #[dyn_trait]
+/// pub trait Meta {
+///     fn merge(tree: Tree<Self>) -> Self;
+/// }
+/// ```
+#[proc_macro_derive(DynMap)]
+pub fn dyn_map(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    dyn_map::derive(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// This is a derive macro for stable type names.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use dyn_derive::*;
+/// use dyn_std::DynName;
+/// 
+/// #[derive(DynName)]
+/// #[dyn_name = "geo.circle/v1"]
+/// pub struct Circle(f64);
+/// 
+/// assert_eq!(Circle::NAME, "geo.circle/v1");
+/// ```
+#[proc_macro_derive(DynName, attributes(dyn_name))]
+pub fn dyn_name(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    dyn_name::derive(input).unwrap_or_else(syn::Error::into_compile_error).into()
 }
 
 #[cfg(test)]
@@ -35,7 +143,7 @@ mod test {
     use std::env::args_os;
     use std::ffi::OsString;
     use std::fs::{create_dir_all, read_to_string, write};
-    use std::mem::replace;
+    use std::mem::take;
     use std::path::{Path, PathBuf};
 
     use pretty_assertions::StrComparison;
@@ -44,19 +152,45 @@ mod test {
     use quote::{quote, ToTokens};
     use walkdir::WalkDir;
 
-    use crate::dyn_trait::transform;
+    use crate::{dyn_adapter, dyn_impl, dyn_map, dyn_name, dyn_trait};
 
-    fn transform_input(input: TokenStream) -> TokenStream {
-        let mut item: syn::ItemTrait = syn::parse2(input).unwrap();
-        let attrs = replace(&mut item.attrs, vec![]);
+    fn transform_input(input: TokenStream) -> syn::Result<TokenStream> {
+        let item: syn::Item = syn::parse2(input).unwrap();
+        let mut item = match item {
+            syn::Item::Trait(item) => item,
+            syn::Item::Struct(_) | syn::Item::Enum(_) => {
+                let mut input: syn::DeriveInput = syn::parse2(item.into_token_stream()).unwrap();
+                let derive = input.attrs.remove(0);
+                match derive.to_token_stream().to_string().as_str() {
+                    "# [derive (DynMap)]" => return dyn_map::derive(input),
+                    "# [derive (DynName)]" => return dyn_name::derive(input),
+                    derive => panic!("unexpected derive {}", derive),
+                }
+            },
+            syn::Item::Impl(mut item) => {
+                let attrs = take(&mut item.attrs);
+                assert_eq!(attrs.len(), 1);
+                assert!(attrs[0].path().is_ident("dyn_impl"));
+                let attr = match &attrs[0].meta {
+                    syn::Meta::List(list) => list.tokens.clone(),
+                    _ => quote! {},
+                };
+                return dyn_impl::transform(attr, item)
+            },
+            _ => panic!("expect trait, impl, struct or enum"),
+        };
+        let attrs = take(&mut item.attrs);
         assert_eq!(attrs.len(), 1);
-        assert_eq!(attrs[0].path().to_token_stream().to_string(), "dyn_trait".to_string());
         let attr = match &attrs[0].meta {
             syn::Meta::Path(_) => quote! {},
             syn::Meta::List(list) => list.tokens.clone(),
             syn::Meta::NameValue(_) => unimplemented!(),
         };
-        transform(attr, item)
+        match attrs[0].path().to_token_stream().to_string().as_str() {
+            "dyn_trait" => dyn_trait::transform(attr, item),
+            "dyn_adapter" => dyn_adapter::transform(attr, item),
+            name => panic!("unexpected attribute {}", name),
+        }
     }
 
     struct TestDiff {
@@ -80,7 +214,8 @@ mod test {
             let path = input_path.strip_prefix(input_dir).unwrap();
             let output_path = Path::new(output_dir).join(path);
             let input = read_to_string(input_path).unwrap().parse().unwrap();
-            let actual = unparse(&syn::parse2(transform_input(input)).unwrap());
+            let output = transform_input(input).unwrap_or_else(syn::Error::into_compile_error);
+            let actual = unparse(&syn::parse2(output).unwrap());
             let expect_result = read_to_string(&output_path);
             if let Ok(expect) = &expect_result {
                 if expect == &actual {
@@ -1,7 +1,11 @@
+use std::cell::Cell;
+use std::fmt::Display;
+use std::rc::Rc;
+
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 
-use crate::{generics::GenericsData, subst_self::subst_self};
+use crate::{errors::Errors, generics::GenericsData, subst_self::subst_self};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum FnTrait {
@@ -18,6 +22,48 @@ enum RefType {
     None,
 }
 
+fn is_dyn_pointer(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Reference(reference) => {
+            reference.mutability.is_some() && matches!(reference.elem.as_ref(), syn::Type::TraitObject(_))
+        },
+        syn::Type::Path(tp) => {
+            let last = tp.path.segments.last().unwrap();
+            let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+                return false
+            };
+            last.ident == "Box" && matches!(args.args.first(), Some(syn::GenericArgument::Type(syn::Type::TraitObject(_))))
+        },
+        _ => false,
+    }
+}
+
+/// Returns `true` if `path` is `SmallVec` of the `smallvec` crate, which is mapped by the elements of its array.
+fn is_small_vec(path: &syn::Path) -> bool {
+    let segments = path.segments.iter().map(|segment| segment.ident.to_string()).collect::<Vec<_>>();
+    match segments.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["SmallVec"] => path.leading_colon.is_none(),
+        ["smallvec", "SmallVec"] => true,
+        _ => false,
+    }
+}
+
+/// Returns the number of mapped type arguments of hashed collections, whose hasher argument is not mapped.
+fn hashed_arity(path: &syn::Path) -> Option<usize> {
+    let segments = path.segments.iter().map(|segment| segment.ident.to_string()).collect::<Vec<_>>();
+    let (module, ident) = match segments.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        [ident] if path.leading_colon.is_none() => (None, ident),
+        ["std", "collections", ident] => (Some("std"), ident),
+        [module @ ("hashbrown" | "indexmap"), ident] => (Some(module), ident),
+        _ => return None,
+    };
+    match (module, ident) {
+        (None | Some("std" | "hashbrown"), "HashMap") | (None | Some("indexmap"), "IndexMap") => Some(2),
+        (None | Some("std" | "hashbrown"), "HashSet") | (None | Some("indexmap"), "IndexSet") => Some(1),
+        _ => None,
+    }
+}
+
 pub enum Destruct {
     Preserve(TokenStream),
     Tuple(TokenStream),
@@ -34,6 +80,15 @@ pub struct Context<'i> {
     ref_type: RefType,
     polarity: bool,
     depth: usize,
+    // whether converted values live until the call
+    frame: bool,
+    // whether the pointer is wrapped in Pin
+    pinned: bool,
+    // whether some downcast cannot be checked
+    unchecked: Rc<Cell<bool>>,
+    // how arguments of other implementations return early, instead of panicking
+    mismatch: Option<TokenStream>,
+    errors: Errors,
 }
 
 impl Clone for Context<'_> {
@@ -42,22 +97,47 @@ impl Clone for Context<'_> {
             generics: self.generics,
             polarity: self.polarity,
             depth: self.depth,
+            frame: self.frame,
+            unchecked: self.unchecked.clone(),
+            mismatch: self.mismatch.clone(),
+            errors: self.errors.clone(),
             // clear ref
             ref_type: RefType::None,
+            pinned: false,
         }
     }
 }
 
 impl<'i> Context<'i> {
-    pub fn new(generics: &'i GenericsData) -> Self {
+    pub fn new(generics: &'i GenericsData, errors: &Errors) -> Self {
         Self {
             generics,
             ref_type: RefType::None,
             polarity: false,
             depth: 0,
+            frame: false,
+            pinned: false,
+            unchecked: Default::default(),
+            mismatch: None,
+            errors: errors.clone(),
         }
     }
 
+    /// Checks arguments before the call, and returns early with `suffix` (such as `?`) on mismatches.
+    pub fn on_mismatch(&mut self, suffix: TokenStream) {
+        self.mismatch = Some(suffix);
+    }
+
+    pub fn is_unchecked(&self) -> bool {
+        self.unchecked.get()
+    }
+
+    /// Records an error, and leaves the expression unchanged so that other errors can be collected.
+    fn fail(&self, tokens: impl ToTokens, message: impl Display, expr: &impl ToTokens) -> (TokenStream, TokenStream, Destruct, bool) {
+        self.errors.push(tokens, message);
+        (expr.to_token_stream(), quote![], Default::default(), false)
+    }
+
     fn subst_ident(&self, ty: &mut syn::Type, stmts: &mut TokenStream, offset: &mut usize, has_match: &mut bool) -> (TokenStream, TokenStream) {
         let char = (b'a' + (self.depth as u8 - 1)) as char;
         let ident = format_ident!("{}{}", char, *offset + 1);
@@ -90,6 +170,7 @@ impl<'i> Context<'i> {
             let mut ctx = self.clone();
             ctx.polarity ^= true;
             ctx.depth += 1;
+            ctx.frame = true;
             let (pat, expr) = ctx.subst_ident(ty, &mut stmts, &mut offset, &mut has_match);
             offset += 1;
             exprs.push(expr);
@@ -98,6 +179,7 @@ impl<'i> Context<'i> {
         let expr = quote! { #expr(#(#exprs),*) };
         let mut ctx = self.clone();
         ctx.depth += 1;
+        ctx.frame = false;
         let (new_expr, inner_stmts, destruct, has_output_match) = match output {
             syn::ReturnType::Type(_, ty) => ctx.subst(ty, &expr, &mut offset),
             syn::ReturnType::Default => (expr.to_token_stream(), quote! {}, Default::default(), false),
@@ -110,59 +192,90 @@ impl<'i> Context<'i> {
         (new_expr, stmts, params, has_match)
     }
 
+    fn subst_closure(&self, ty_inst: &mut syn::Type) -> (TokenStream, bool) {
+        let mut ty_cons = ty_inst.clone();
+        subst_self(&mut ty_cons, &syn::parse_quote! { Factory });
+        let mut ctx = self.clone();
+        ctx.depth += 1;
+        ctx.frame = false;
+        let count = self.errors.len();
+        let tokens = ty_inst.to_token_stream();
+        let (expr, stmts, destruct, has_inner) = ctx.subst(ty_inst, &quote! { x }, &mut 0);
+        let pat = match destruct {
+            Destruct::Preserve(modifier) => quote! { #modifier x },
+            Destruct::Tuple(pat) => pat,
+        };
+        (match has_inner {
+            true => match self.polarity {
+                true => quote! { |#pat: #ty_inst| -> #ty_cons { #stmts #expr } },
+                false => quote! { |#pat: #ty_cons| -> #ty_inst { #stmts #expr } },
+            },
+            false => {
+                // `Self` is left in a type that cannot be mapped
+                if ty_inst != &ty_cons && self.errors.len() == count {
+                    self.errors.push(tokens, "unsupported use of `Self` in trait methods");
+                }
+                quote! { |x: #ty_inst| x }
+            },
+        }, has_inner)
+    }
+
     fn subst_map<'j>(&self, inputs: impl Iterator<Item = &'j mut syn::Type>, expr: &impl ToTokens) -> (TokenStream, TokenStream, Destruct, bool) {
         let mut has_match = false;
         let args = inputs.map(|ty_inst| {
-            let mut ty_cons = ty_inst.clone();
-            subst_self(&mut ty_cons, &syn::parse_quote! { Factory });
-            let mut ctx = self.clone();
-            ctx.depth += 1;
-            let (expr, stmts, destruct, has_inner) = ctx.subst(ty_inst, &quote! { x }, &mut 0);
-            let pat = match destruct {
-                Destruct::Preserve(modifier) => quote! { #modifier x },
-                Destruct::Tuple(pat) => pat,
-            };
+            let (closure, has_inner) = self.subst_closure(ty_inst);
             has_match |= has_inner;
-            Some(match has_inner {
-                true => match self.polarity {
-                    true => quote! { |#pat: #ty_inst| -> #ty_cons { #stmts #expr } },
-                    false => quote! { |#pat: #ty_cons| -> #ty_inst { #stmts #expr } },
-                },
-                false => {
-                    assert_eq!(ty_inst, &ty_cons);
-                    assert!(stmts.is_empty());
-                    quote! { |x: #ty_inst| x }
-                },
-            })
+            closure
         }).collect::<Vec<_>>();
-        let len = args.len();
-        let ident = format_ident!("Map{}", len);
-        return (quote! { ::dyn_std::map::#ident::map(#expr, #(#args),*) }, quote![], Default::default(), has_match)
+        let ident = format_ident!("Map{}", args.len());
+        (quote! { ::dyn_std::map::#ident::map(#expr, #(&mut #args),*) }, quote![], Default::default(), has_match)
     }
 
     pub fn subst(&self, ty: &mut syn::Type, expr: &impl ToTokens, offset: &mut usize) -> (TokenStream, TokenStream, Destruct, bool) {
+        // errors point at the original type, before `Self` is replaced
+        let tokens = ty.to_token_stream();
         match ty {
             syn::Type::Path(tp) => 'k: {
                 if tp.qself.is_none() && tp.path.segments.len() == 1 {
                     let last = tp.path.segments.last_mut().unwrap();
                     if last.ident == "Box" {
                         let syn::PathArguments::AngleBracketed(args) = &mut last.arguments else {
-                            panic!("expect angle-bracketed arguments in Box type")
+                            return self.fail(tokens, "expected a single type argument in `Box`", expr)
                         };
-                        if args.args.len() != 1 {
-                            panic!("expect exactly one argument in Box type")
+                        let (1, Some(syn::GenericArgument::Type(ty))) = (args.args.len(), args.args.first_mut()) else {
+                            return self.fail(tokens, "expected a single type argument in `Box`", expr)
+                        };
+                        let result = if let syn::Type::Slice(slice) = ty {
+                            self.subst_map([slice.elem.as_mut()].into_iter(), expr)
+                        } else {
+                            let mut ctx = self.clone();
+                            ctx.ref_type = RefType::Box;
+                            ctx.pinned = self.pinned;
+                            ctx.subst(ty, expr, offset)
+                        };
+                        if !result.3 {
+                            break 'k
                         }
-                        let syn::GenericArgument::Type(ty) = args.args.first_mut().unwrap() else {
-                            panic!("expect type argument in Box type")
+                        if self.ref_type != RefType::None {
+                            return self.fail(tokens, "nested references to `Self` are not supported in trait methods", expr)
+                        }
+                        return result
+                    }
+                    if last.ident == "Pin" {
+                        let syn::PathArguments::AngleBracketed(args) = &mut last.arguments else {
+                            return self.fail(tokens, "expected a type argument in `Pin`", expr)
+                        };
+                        let Some(syn::GenericArgument::Type(ty)) = args.args.first_mut() else {
+                            return self.fail(tokens, "expected a type argument in `Pin`", expr)
                         };
                         let mut ctx = self.clone();
-                        ctx.ref_type = RefType::Box;
+                        ctx.pinned = true;
                         let result = ctx.subst(ty, expr, offset);
                         if !result.3 {
                             break 'k
                         }
-                        if self.ref_type != RefType::None {
-                            unimplemented!("nested reference in trait method")
+                        if !is_dyn_pointer(ty) {
+                            return self.fail(tokens, "only `Pin<Box<dyn ..>>` and `Pin<&mut dyn ..>` are supported in trait methods", expr)
                         }
                         return result
                     }
@@ -170,6 +283,11 @@ impl<'i> Context<'i> {
                 let result = self.generics.test(tp, self.ref_type != RefType::None);
                 if let Some((repl, repl2)) = result {
                     *ty = repl;
+                    // only arguments of the method itself can return early
+                    let stmts = match &self.mismatch {
+                        Some(suffix) if self.polarity && self.frame && self.depth == 1 => quote! { #repl2::check(&*#expr)#suffix; },
+                        _ => quote![],
+                    };
                     return (if self.polarity {
                         match self.ref_type {
                             RefType::Mut => quote! { #repl2::downcast_mut(#expr) },
@@ -179,23 +297,43 @@ impl<'i> Context<'i> {
                         }
                     } else {
                         match self.ref_type {
+                            RefType::Mut => quote! { ::dyn_std::Instance::from_mut(#expr) },
+                            RefType::Ref => quote! { ::dyn_std::Instance::from_ref(#expr) },
+                            RefType::Box => return self.fail(tokens, "`Box<Self>` is not supported in trait method return types", expr),
                             RefType::None => quote! { Box::new(::dyn_std::Instance::new(#expr)) },
-                            _ => unimplemented!("reference in trait method return type"),
                         }
-                    }, quote![], Default::default(), true)
+                    }, stmts, Default::default(), true)
                 }
-                let syn::PathArguments::AngleBracketed(args) = &mut tp.path.segments.last_mut().unwrap().arguments else {
+                let is_small_vec = is_small_vec(&tp.path);
+                let hashed_arity = hashed_arity(&tp.path);
+                let last = tp.path.segments.last_mut().unwrap();
+                let syn::PathArguments::AngleBracketed(args) = &mut last.arguments else {
                     break 'k
                 };
+                let ident = &last.ident;
                 let args = args.args
                     .iter_mut()
                     .filter_map(|arg| {
+                        // const arguments are literals or blocks, such as `{ CAP }`, as traits have no const parameters
                         match arg {
-                            syn::GenericArgument::Type(ty) => Some(ty),
+                            syn::GenericArgument::Type(ty) => {
+                                // `SmallVec<[T; N]>` is mapped by its elements
+                                match (is_small_vec, ty) {
+                                    (true, syn::Type::Array(array)) => Some(array.elem.as_mut()),
+                                    (_, ty) => Some(ty),
+                                }
+                            },
                             _ => None,
                         }
-                    });
-                let result = self.subst_map(args, expr);
+                    })
+                    // the hasher of `HashMap<K, V, H>` is kept as it is
+                    .take(hashed_arity.unwrap_or(usize::MAX))
+                    .collect::<Vec<_>>();
+                if args.len() > ::dyn_std::map::MAX_ARITY {
+                    let message = format!("more than {} type arguments are not supported in trait methods", ::dyn_std::map::MAX_ARITY);
+                    return self.fail(ident, message, expr)
+                }
+                let result = self.subst_map(args.into_iter(), expr);
                 if !result.3 {
                     break 'k
                 }
@@ -207,12 +345,13 @@ impl<'i> Context<'i> {
                     Some(_) => RefType::Mut,
                     None => RefType::Ref,
                 };
+                ctx.pinned = self.pinned;
                 let result = ctx.subst(&mut reference.elem, expr, offset);
                 if !result.3 {
                     break 'k
                 }
                 if self.ref_type != RefType::None {
-                    unimplemented!("nested reference in trait method")
+                    return self.fail(tokens, "nested references to `Self` are not supported in trait methods", expr)
                 }
                 return result
             },
@@ -241,24 +380,104 @@ impl<'i> Context<'i> {
                 let ctx = self.clone();
                 let result = ctx.subst(&mut slice.elem, expr, offset);
                 if result.3 {
-                    unimplemented!("slice types in trait methods")
+                    return self.fail(tokens, "slices of `Self` are not supported in trait methods, use `Box<[Self]>` or `Vec<Self>` instead", expr)
                 }
             },
             syn::Type::Ptr(ptr) => {
+                if let syn::Type::Path(tp) = ptr.elem.as_mut() {
+                    let mut elem_cons = syn::Type::Path(tp.clone());
+                    subst_self(&mut elem_cons, &syn::parse_quote! { Factory });
+                    if let Some((repl, repl2)) = self.generics.test(tp, true) {
+                        if self.ref_type != RefType::None {
+                            return self.fail(tokens, "nested references to `Self` are not supported in trait methods", expr)
+                        }
+                        let mutability = match ptr.mutability {
+                            Some(_) => quote! { mut },
+                            None => quote! { const },
+                        };
+                        *ptr.elem = repl.clone();
+                        return (if self.polarity {
+                            // raw pointers cannot be dereferenced to check their types
+                            self.unchecked.set(true);
+                            quote! { #expr as *#mutability #elem_cons }
+                        } else {
+                            quote! { #expr as *#mutability #repl2 as *#mutability #repl }
+                        }, quote![], Default::default(), true)
+                    }
+                }
                 let ctx = self.clone();
                 let result = ctx.subst(&mut ptr.elem, expr, offset);
                 if result.3 {
-                    unimplemented!("pointers in trait methods")
+                    return self.fail(tokens, "raw pointers are only supported as `*const Self` and `*mut Self` in trait methods", expr)
                 }
             },
             syn::Type::ImplTrait(_) => {
-                unimplemented!("impl trait in trait methods")
+                return self.fail(tokens, "`impl Trait` is not supported in trait methods", expr)
             },
             syn::Type::TraitObject(trait_object) => 'k: {
                 for bound in &mut trait_object.bounds {
                     let syn::TypeParamBound::Trait(bound) = bound else {
                         continue;
                     };
+                    let last = bound.path.segments.last_mut().unwrap();
+                    if let syn::PathArguments::AngleBracketed(args) = &mut last.arguments {
+                        let mut has_match = false;
+                        let mut item = None;
+                        for arg in &mut args.args {
+                            match arg {
+                                syn::GenericArgument::Type(syn::Type::Path(tp)) => {
+                                    let Some((repl, _)) = self.generics.test(tp, true) else {
+                                        continue;
+                                    };
+                                    *arg = syn::GenericArgument::Type(repl);
+                                    has_match = true;
+                                },
+                                syn::GenericArgument::AssocType(assoc) => {
+                                    let (closure, has_inner) = self.subst_closure(&mut assoc.ty);
+                                    if has_inner {
+                                        item = Some(closure);
+                                    }
+                                },
+                                _ => {},
+                            }
+                        }
+                        if let Some(closure) = item {
+                            let map = match last.ident.to_string().as_str() {
+                                "Iterator" | "DoubleEndedIterator" | "ExactSizeIterator" => quote! { ::core::iter::Iterator::map },
+                                "Future" | "Stream" => quote! { ::dyn_std::future::Map::new },
+                                _ => return self.fail(tokens, "associated types containing `Self` are only supported in iterators, futures and streams", expr),
+                            };
+                            // values are mapped lazily as they are pulled or polled
+                            let value = quote! { #map(#expr, #closure) };
+                            return match (self.polarity, &self.ref_type) {
+                                (_, RefType::Box) if self.pinned => (quote! { Box::pin(#value) }, quote![], Default::default(), true),
+                                (_, RefType::Box) => (quote! { Box::new(#value) }, quote![], Default::default(), true),
+                                (true, RefType::Mut) if !self.frame => {
+                                    self.fail(tokens, "mutable references to iterators, futures and streams are only supported in method arguments", expr)
+                                },
+                                (true, RefType::Mut) if self.pinned => (quote! { ::core::pin::pin!(#value) }, quote![], Default::default(), true),
+                                (true, RefType::Mut) => (quote! { &mut #value }, quote![], Default::default(), true),
+                                (false, RefType::Mut) => {
+                                    self.fail(tokens, "mutable references to iterators, futures and streams are not supported in trait method return types", expr)
+                                },
+                                (_, RefType::Ref) => self.fail(tokens, "shared references to iterators, futures and streams are not supported in trait methods", expr),
+                                (_, RefType::None) => self.fail(tokens, "trait objects must be behind `&mut` or `Box` in trait methods", expr),
+                            }
+                        }
+                        if !has_match {
+                            continue;
+                        }
+                        if !self.polarity {
+                            return self.fail(tokens, "trait objects parameterized by `Self` are not supported in trait method return types", expr)
+                        }
+                        // implemented by #[dyn_adapter]
+                        return (match self.ref_type {
+                            RefType::Box => quote! { Box::new(::dyn_std::adapt::Adapter(#expr)) },
+                            RefType::Mut => quote! { &mut ::dyn_std::adapt::Adapter(#expr) },
+                            RefType::Ref => quote! { &::dyn_std::adapt::Adapter(#expr) },
+                            RefType::None => return self.fail(tokens, "trait objects must be behind `&`, `&mut` or `Box` in trait methods", expr),
+                        }, quote![], Default::default(), true)
+                    }
                     if bound.path.segments.len() != 1 {
                         continue;
                     }
@@ -270,7 +489,7 @@ impl<'i> Context<'i> {
                         _ => continue,
                     };
                     let syn::PathArguments::Parenthesized(args) = &mut last.arguments else {
-                        panic!("expect parenthesized arguments in {} trait", last.ident)
+                        return self.fail(tokens, format!("expected parenthesized arguments in `{}`", last.ident), expr)
                     };
                     let (expr, stmts, params, has_match) = self.subst_fn(args.inputs.iter_mut(), &mut args.output, expr);
                     if !has_match {
@@ -288,10 +507,63 @@ impl<'i> Context<'i> {
                         }), true),
                         RefType::Mut => (quote! { &mut #closure }, quote![], Default::default(), true),
                         RefType::Ref => (quote! { & #closure }, quote![], Default::default(), true),
-                        RefType::None => unreachable!("expect &dyn, &mut dyn or Box<dyn>"),
+                        RefType::None => self.fail(tokens, "trait objects must be behind `&`, `&mut` or `Box` in trait methods", &expr),
                     }
                 }
             },
+            syn::Type::BareFn(bare_fn) => 'k: {
+                let mut ty_cons = syn::Type::BareFn(bare_fn.clone());
+                subst_self(&mut ty_cons, &syn::parse_quote! { Factory });
+                let inputs = bare_fn.inputs.iter_mut().map(|arg| &mut arg.ty);
+                let (expr_fn, stmts, params, has_match) = self.subst_fn(inputs, &mut bare_fn.output, &quote! { f });
+                if !has_match {
+                    break 'k
+                }
+                if self.ref_type != RefType::None {
+                    return self.fail(tokens, "nested references to `Self` are not supported in trait methods", expr)
+                }
+                if bare_fn.unsafety.is_some() || bare_fn.abi.is_some() || bare_fn.variadic.is_some() {
+                    return self.fail(tokens, "unsafe or extern function pointers are not supported in trait methods", expr)
+                }
+                let lifetimes = &bare_fn.lifetimes;
+                let inputs = bare_fn.inputs.iter().map(|arg| &arg.ty);
+                let output = &bare_fn.output;
+                let dyn_fn = quote! { dyn #lifetimes Fn(#(#inputs),*) #output };
+                let body = if stmts.is_empty() {
+                    quote! { #expr_fn }
+                } else {
+                    quote! { { #stmts #expr_fn } }
+                };
+                if !self.polarity {
+                    *ty = syn::parse_quote! { Box<#dyn_fn> };
+                    return (quote! {{
+                        let f = #expr;
+                        Box::new(move |#(#params),*| #body)
+                    }}, quote![], Default::default(), true)
+                }
+                if !self.frame {
+                    return self.fail(tokens, "function pointers are only supported in method arguments", expr)
+                }
+                if self.generics.dynamic {
+                    return self.fail(tokens, "function pointers are not supported in traits with dynamic parameters", expr)
+                }
+                *ty = syn::parse_quote! { &#dyn_fn };
+                // function pointers cannot capture, so the trait object is passed through a scope,
+                // keyed by a local type which is unique to the call site, and by the generics of the impl,
+                // as the local type is shared by all their instantiations
+                let ident = format_ident!("__Scope{}", expr.to_token_stream().to_string().to_uppercase());
+                let type_params = &self.generics.params;
+                let key = quote! { ::dyn_std::func::Key<(#ident, Factory, #(#type_params,)*)> };
+                return (
+                    quote! { (|#(#params),*| ::dyn_std::func::with::<#key, #dyn_fn, _>(|f| #body)) as #ty_cons },
+                    quote! {
+                        enum #ident {}
+                        let _scope = unsafe { ::dyn_std::func::enter::<#key, _>(&#expr) };
+                    },
+                    Default::default(),
+                    true,
+                )
+            },
             _ => {},
         }
         (expr.to_token_stream(), quote![], Default::default(), false)
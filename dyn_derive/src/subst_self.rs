@@ -6,11 +6,8 @@ fn subst_self_in_type_path(path: &mut syn::Path, repl: &syn::Ident) {
         match &mut segment.arguments {
             syn::PathArguments::AngleBracketed(args) => {
                 for arg in &mut args.args {
-                    match arg {
-                        syn::GenericArgument::Type(ty) => {
-                            subst_self(ty, repl);
-                        },
-                        _ => {},
+                    if let syn::GenericArgument::Type(ty) = arg {
+                        subst_self(ty, repl);
                     }
                 }
             },
@@ -60,21 +57,15 @@ pub fn subst_self(ty: &mut syn::Type, repl: &syn::Ident) {
         },
         syn::Type::ImplTrait(impl_trait) => {
             for bound in &mut impl_trait.bounds {
-                match bound {
-                    syn::TypeParamBound::Trait(bound) => {
-                        subst_self_in_type_path(&mut bound.path, repl)
-                    },
-                    _ => {},
+                if let syn::TypeParamBound::Trait(bound) = bound {
+                    subst_self_in_type_path(&mut bound.path, repl)
                 }
             }
         },
         syn::Type::TraitObject(trait_object) => {
             for bound in &mut trait_object.bounds {
-                match bound {
-                    syn::TypeParamBound::Trait(bound) => {
-                        subst_self_in_type_path(&mut bound.path, repl)
-                    },
-                    _ => {},
+                if let syn::TypeParamBound::Trait(bound) = bound {
+                    subst_self_in_type_path(&mut bound.path, repl)
                 }
             }
         },
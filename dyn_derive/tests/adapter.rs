@@ -0,0 +1,61 @@
+use dyn_derive::*;
+use dyn_std::Instance;
+
+#[dyn_adapter]
+pub trait Visitor<#[dynamic] T> {
+    fn visit(&mut self, node: &T) -> bool;
+}
+
+#[dyn_adapter]
+pub trait Observer<#[dynamic] T> {
+    fn notify(&self, node: &T) -> i32;
+}
+
+#[dyn_trait]
+pub trait Meta {
+    fn get(&self) -> i32;
+    fn accept(&self, visitor: &mut dyn Visitor<Self>) -> bool;
+    fn observe(&self, observer: Box<dyn Observer<Self>>) -> i32;
+}
+
+pub struct MetaImpl(i32);
+
+impl Meta for MetaImpl {
+    fn get(&self) -> i32 {
+        self.0
+    }
+
+    fn accept(&self, visitor: &mut dyn Visitor<Self>) -> bool {
+        visitor.visit(self) && visitor.visit(&MetaImpl(self.0 + 1))
+    }
+
+    fn observe(&self, observer: Box<dyn Observer<Self>>) -> i32 {
+        observer.notify(self)
+    }
+}
+
+struct Collect(Vec<i32>);
+
+impl Visitor<dyn MetaInstance> for Collect {
+    fn visit(&mut self, node: &dyn MetaInstance) -> bool {
+        self.0.push(node.get());
+        true
+    }
+}
+
+struct Double;
+
+impl Observer<dyn MetaInstance> for Double {
+    fn notify(&self, node: &dyn MetaInstance) -> i32 {
+        node.get() * 2
+    }
+}
+
+#[test]
+fn main() {
+    let inst: Box<dyn MetaInstance> = Box::new(Instance::new(MetaImpl(42)));
+    let mut collect = Collect(vec![]);
+    assert!(inst.accept(&mut collect));
+    assert_eq!(collect.0, vec![42, 43]);
+    assert_eq!(inst.observe(Box::new(Double)), 84);
+}
@@ -0,0 +1,25 @@
+use std::fmt::Debug;
+use std::ops::AddAssign;
+
+use dyn_derive::*;
+
+#[dyn_trait]
+pub trait Meta: Debug + AddAssign {}
+
+#[derive(Debug)]
+pub struct MetaImpl(String);
+
+impl MetaInstance for MetaImpl {}
+
+impl AddAssign for MetaImpl {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += &rhs.0;
+    }
+}
+
+#[test]
+fn main() {
+    let mut meta: Box<dyn MetaInstance> = Box::new(MetaImpl("114".into()));
+    meta += Box::new(MetaImpl("514".into())) as Box<dyn MetaInstance>;
+    assert_eq!(format!("{:?}", meta), "MetaImpl(\"114514\")");
+}
@@ -0,0 +1,53 @@
+use dyn_derive::*;
+use dyn_std::{Constructor, Instance};
+
+#[dyn_trait(instance = DynShape, constructor = ShapeFactory, vis = pub(crate), alias = BoxShape)]
+pub trait Shape: Clone {
+    fn area(&self) -> f64;
+    fn scale(self, factor: f64) -> Self;
+    fn unit() -> Self;
+}
+
+#[derive(Clone)]
+pub struct Square(f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+
+    fn scale(self, factor: f64) -> Self {
+        Self(self.0 * factor)
+    }
+
+    fn unit() -> Self {
+        Self(1.0)
+    }
+}
+
+#[test]
+fn main() {
+    let factory: Box<dyn ShapeFactory> = Box::new(Constructor::<Square>::new());
+    let unit: BoxShape = factory.unit();
+    assert_eq!(unit.area(), 1.0);
+    let shape: BoxShape = Box::new(Instance::new(Square(2.0)));
+    let shape = shape.clone().scale(1.5);
+    assert_eq!(shape.area(), 9.0);
+}
+
+#[dyn_trait(constructor_only)]
+pub trait Plugin {
+    fn name() -> String;
+}
+
+impl Plugin for Square {
+    fn name() -> String {
+        "square".into()
+    }
+}
+
+#[test]
+fn constructor_only() {
+    let plugins: Vec<Box<dyn PluginConstructor>> = vec![Box::new(Constructor::<Square>::new())];
+    assert_eq!(plugins[0].name(), "square");
+}
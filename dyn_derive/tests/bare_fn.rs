@@ -0,0 +1,102 @@
+use std::cell::Cell;
+use std::cmp::Ordering;
+
+use dyn_derive::*;
+use dyn_std::{Constructor, Instance};
+
+#[dyn_trait]
+pub trait Meta {
+    fn sort_by(&mut self, cmp: fn(&Self, &Self) -> Ordering) -> Ordering;
+    fn apply(value: Self, hook: fn(Self) -> Self) -> Self;
+    fn nest(value: Self, hook: fn(Self) -> Self) -> Self;
+    fn get(&self) -> i32;
+}
+
+pub struct MetaImpl(i32);
+
+impl Meta for MetaImpl {
+    fn sort_by(&mut self, cmp: fn(&Self, &Self) -> Ordering) -> Ordering {
+        cmp(self, &MetaImpl(0))
+    }
+
+    fn apply(value: Self, hook: fn(Self) -> Self) -> Self {
+        hook(hook(value))
+    }
+
+    fn nest(value: Self, hook: fn(Self) -> Self) -> Self {
+        if value.0 >= 100 {
+            return hook(value)
+        }
+        // passes the function pointer back to the same call site
+        let cons: &dyn MetaConstructor = &Constructor::<MetaImpl>::new();
+        let value = cons.nest(Box::new(Instance::new(MetaImpl(value.0 + 100))), &|x| {
+            Box::new(Instance::new(hook(Instance::downcast(x))))
+        });
+        hook(Instance::downcast(value))
+    }
+
+    fn get(&self) -> i32 {
+        self.0
+    }
+}
+
+#[dyn_trait]
+pub trait Visit<T> {
+    fn visit(&self, f: fn(&Self) -> usize) -> usize;
+    fn get(&self) -> T;
+}
+
+pub struct Pair;
+
+type Saved = Option<fn(&Pair) -> usize>;
+
+thread_local! {
+    static SAVED: Cell<Saved> = const { Cell::new(None) };
+}
+
+impl Visit<u64> for Pair {
+    fn visit(&self, f: fn(&Self) -> usize) -> usize {
+        SAVED.set(Some(f));
+        let other: Box<dyn VisitInstance<String>> = Box::new(Instance::new(Pair));
+        other.visit(&|x| x.get().len())
+    }
+
+    fn get(&self) -> u64 {
+        42
+    }
+}
+
+impl Visit<String> for Pair {
+    fn visit(&self, _: fn(&Self) -> usize) -> usize {
+        // calls the function pointer of the other instantiation
+        SAVED.get().unwrap()(self)
+    }
+
+    fn get(&self) -> String {
+        "hello".into()
+    }
+}
+
+#[test]
+fn main() {
+    let cons: Box<dyn MetaConstructor> = Box::new(Constructor::<MetaImpl>::new());
+    let mut inst: Box<dyn MetaInstance> = Box::new(Instance::new(MetaImpl(42)));
+    assert_eq!(inst.sort_by(&|a, b| a.get().cmp(&b.get())), Ordering::Greater);
+    assert_eq!(inst.sort_by(&|a, b| b.get().cmp(&a.get())), Ordering::Less);
+    let offset = 1;
+    let inst = cons.apply(inst, &|x| Box::new(Instance::new(MetaImpl(x.get() + offset))));
+    assert_eq!(inst.get(), 44);
+}
+
+#[test]
+fn nested() {
+    let cons: Box<dyn MetaConstructor> = Box::new(Constructor::<MetaImpl>::new());
+    let inst = cons.nest(Box::new(Instance::new(MetaImpl(1))), &|x| Box::new(Instance::new(MetaImpl(x.get() * 2))));
+    assert_eq!(inst.get(), 404);
+}
+
+#[test]
+fn generic() {
+    let inst: Box<dyn VisitInstance<u64>> = Box::new(Instance::new(Pair));
+    assert_eq!(inst.visit(&|x| x.get() as usize), 42);
+}
@@ -0,0 +1,61 @@
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeSet, HashMap, LinkedList, VecDeque};
+use std::hash::BuildHasherDefault;
+use std::sync::Mutex;
+
+use dyn_derive::*;
+use dyn_std::{Constructor, Instance};
+
+#[dyn_trait]
+pub trait Meta {
+    fn deque(items: VecDeque<Self>) -> LinkedList<Self>;
+    fn boxed(items: Box<[Self]>) -> Box<[(Self, i32)]>;
+    fn cell(item: RefCell<Self>) -> Mutex<Self>;
+    fn hasher(items: HashMap<i32, Self, BuildHasherDefault<DefaultHasher>>) -> BTreeSet<i32>;
+    fn get(&self) -> i32;
+}
+
+pub struct MetaImpl(i32);
+
+impl Meta for MetaImpl {
+    fn deque(items: VecDeque<Self>) -> LinkedList<Self> {
+        items.into_iter().rev().collect()
+    }
+
+    fn boxed(items: Box<[Self]>) -> Box<[(Self, i32)]> {
+        items.into_vec().into_iter().enumerate().map(|(i, x)| (x, i as i32)).collect()
+    }
+
+    fn cell(item: RefCell<Self>) -> Mutex<Self> {
+        item.borrow_mut().0 += 1;
+        Mutex::new(item.into_inner())
+    }
+
+    fn hasher(items: HashMap<i32, Self, BuildHasherDefault<DefaultHasher>>) -> BTreeSet<i32> {
+        items.into_iter().map(|(k, v)| k * v.0).collect()
+    }
+
+    fn get(&self) -> i32 {
+        self.0
+    }
+}
+
+fn new(value: i32) -> Box<dyn MetaInstance> {
+    Box::new(Instance::new(MetaImpl(value)))
+}
+
+#[test]
+fn main() {
+    let cons: Box<dyn MetaConstructor> = Box::new(Constructor::<MetaImpl>::new());
+    let list = cons.deque(VecDeque::from([new(1), new(2)]));
+    assert_eq!(list.iter().map(|x| x.get()).collect::<Vec<_>>(), vec![2, 1]);
+    let boxed = cons.boxed(Box::new([new(3), new(4)]));
+    assert_eq!(boxed.iter().map(|(x, i)| (x.get(), *i)).collect::<Vec<_>>(), vec![(3, 0), (4, 1)]);
+    let mutex = cons.cell(RefCell::new(new(5)));
+    assert_eq!(mutex.lock().unwrap().get(), 6);
+    let mut map = HashMap::default();
+    map.insert(2, new(7));
+    map.insert(3, new(8));
+    assert_eq!(cons.hasher(map), BTreeSet::from([14, 24]));
+}
@@ -0,0 +1,124 @@
+use std::any::Any;
+use std::collections::HashSet;
+
+use dyn_derive::*;
+use dyn_std::{Constructor, Downcast, Instance};
+
+#[dyn_trait]
+pub trait Shape {
+    fn area(&self) -> f64;
+    fn unit() -> Self;
+}
+
+pub struct Square(f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+
+    fn unit() -> Self {
+        Square(1.0)
+    }
+}
+
+pub struct Manual;
+
+impl ShapeInstance for Manual {
+    fn area(&self) -> f64 {
+        0.0
+    }
+}
+
+#[test]
+fn instance() {
+    let shape: Box<dyn ShapeInstance> = Box::new(Instance::new(Square(2.0)));
+    let unit = shape.constructor().unwrap().unit();
+    assert!(unit.is::<Square>());
+    assert_eq!(unit.area(), 1.0);
+}
+
+#[test]
+fn manual() {
+    let shape: Box<dyn ShapeInstance> = Box::new(Manual);
+    assert!(shape.constructor().is_none());
+}
+
+#[dyn_trait(Send, Sync)]
+pub trait Token {
+    fn pick() -> Self;
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Red;
+
+impl Token for Red {
+    fn pick() -> Self {
+        Red
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Blue;
+
+impl Token for Blue {
+    fn pick() -> Self {
+        Blue
+    }
+}
+
+static TOKENS: [&(dyn TokenConstructor + Send + Sync); 2] = [&Constructor::<Red>::new(), &Constructor::<Blue>::new()];
+
+#[test]
+fn identity() {
+    let red: &dyn TokenConstructor = &Constructor::<Red>::new();
+    assert_eq!(red, &Constructor::<Red>::new() as &dyn TokenConstructor);
+    assert_ne!(red, &Constructor::<Blue>::new() as &dyn TokenConstructor);
+    assert_eq!(format!("{:?}", red), format!("Constructor<{}>", std::any::type_name::<Red>()));
+    assert_eq!(format!("{:?}", red), format!("{:?}", Constructor::<Red>::new()));
+    let set = TOKENS.into_iter().chain(TOKENS).collect::<HashSet<_>>();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&Constructor::<Blue>::new() as &(dyn TokenConstructor + Send + Sync)));
+}
+
+#[test]
+fn wrap() {
+    let square: &dyn ShapeConstructor = &Constructor::<Square>::new();
+    let value: Box<dyn Any> = Box::new(Square(2.0));
+    let shape = square.wrap(value).ok().unwrap();
+    assert_eq!(shape.area(), 4.0);
+    let value: Box<dyn Any> = Box::new(Manual);
+    let value = square.wrap(value).err().unwrap();
+    assert!(value.is::<Manual>());
+    assert_eq!(square.wrap_ref(&Square(3.0)).map(|shape| shape.area()), Some(9.0));
+    assert!(square.wrap_ref(&Manual).is_none());
+    let square: &(dyn ShapeConstructor + Send + Sync) = &Constructor::<Square>::new();
+    assert_eq!(square.dyn_wrap_ref(&Square(3.0)).map(|shape| shape.area()), Some(9.0));
+    assert!(square.wrap_ref(&Manual).is_none());
+}
+
+// inherent methods do not shadow functions of the original trait
+#[dyn_trait]
+pub trait Wrapper {
+    fn wrap(inner: i32) -> Self;
+    fn wrap_ref(&self) -> &i32;
+}
+
+pub struct Number(i32);
+
+impl Wrapper for Number {
+    fn wrap(inner: i32) -> Self {
+        Number(inner)
+    }
+
+    fn wrap_ref(&self) -> &i32 {
+        &self.0
+    }
+}
+
+#[test]
+fn wrap_name() {
+    let number: &dyn WrapperConstructor = &Constructor::<Number>::new();
+    assert_eq!(number.wrap(42).wrap_ref(), &42);
+    assert_eq!(number.dyn_wrap_ref(&Number(1)).map(|number| *number.wrap_ref()), Some(1));
+}
@@ -0,0 +1,71 @@
+use std::any::TypeId;
+
+use dyn_derive::*;
+use dyn_std::{Downcast, Instance};
+
+#[dyn_trait]
+pub trait Shape {
+    fn area(&self) -> f64;
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Square(f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Circle(f64);
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.0 * self.0
+    }
+}
+
+pub struct Manual;
+
+impl ShapeInstance for Manual {
+    fn area(&self) -> f64 {
+        0.0
+    }
+}
+
+#[test]
+fn instance() {
+    let mut shape: Box<dyn ShapeInstance> = Box::new(Instance::new(Square(2.0)));
+    assert_eq!(shape.type_name(), std::any::type_name::<Instance<Square>>());
+    assert_eq!(shape.type_name_of_inner(), std::any::type_name::<Square>());
+    assert_eq!(shape.type_id_of_inner(), TypeId::of::<Square>());
+    assert!(shape.is::<Square>());
+    assert!(!shape.is::<Circle>());
+    assert_eq!(shape.downcast_ref::<Square>(), Some(&Square(2.0)));
+    assert_eq!(shape.downcast_ref::<Circle>(), None);
+    shape.downcast_mut::<Square>().unwrap().0 = 3.0;
+    assert_eq!(shape.area(), 9.0);
+    let shape = shape.downcast::<Circle>().unwrap_err();
+    assert_eq!(shape.downcast::<Square>().ok(), Some(Square(3.0)));
+}
+
+#[test]
+fn manual() {
+    let mut shape: Box<dyn ShapeInstance> = Box::new(Manual);
+    assert_eq!(shape.type_name_of_inner(), std::any::type_name::<Manual>());
+    assert!(shape.is::<Manual>());
+    assert!(shape.downcast_ref::<Manual>().is_some());
+    assert!(shape.downcast_mut::<Manual>().is_some());
+    assert!(shape.downcast::<Manual>().is_ok());
+}
+
+#[test]
+fn send_sync() {
+    let shape: Box<dyn ShapeInstance + Send> = Box::new(Instance::new(Square(2.0)));
+    assert!(shape.is::<Square>());
+    assert!(shape.constructor().is_some());
+    let shape: Box<dyn ShapeInstance + Send + Sync> = Box::new(Instance::new(Circle(1.0)));
+    assert_eq!(shape.type_name_of_inner(), std::any::type_name::<Circle>());
+    assert_eq!(shape.downcast::<Circle>().ok(), Some(Circle(1.0)));
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::new_ret_no_self, clippy::wrong_self_convention)]
+
 use std::collections::HashMap;
 use std::fmt::Debug;
 
@@ -0,0 +1,132 @@
+use dyn_derive::*;
+
+#[dyn_trait(registry)]
+pub trait Shape {
+    fn area(&self) -> f64;
+    fn unit() -> Self;
+}
+
+pub struct Square(f64);
+
+#[dyn_impl(name = "square")]
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+
+    fn unit() -> Self {
+        Self(1.0)
+    }
+}
+
+pub struct Circle(f64);
+
+#[dyn_impl(name = "circle")]
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.0 * self.0
+    }
+
+    fn unit() -> Self {
+        Self(1.0)
+    }
+}
+
+mod triangle {
+    use dyn_derive::dyn_impl;
+
+    pub struct Triangle(f64);
+
+    #[dyn_impl(name = "triangle")]
+    impl super::Shape for Triangle {
+        fn area(&self) -> f64 {
+            self.0 * self.0 * 3f64.sqrt() / 4.0
+        }
+
+        fn unit() -> Self {
+            Self(1.0)
+        }
+    }
+}
+
+// traits of the same name have separate registries
+mod other {
+    use dyn_derive::*;
+
+    #[dyn_trait(registry)]
+    pub trait Shape {
+        fn sides(&self) -> usize;
+        fn unit() -> Self;
+    }
+
+    pub struct Circle;
+
+    #[dyn_impl(name = "circle")]
+    impl Shape for Circle {
+        fn sides(&self) -> usize {
+            0
+        }
+
+        fn unit() -> Self {
+            Self
+        }
+    }
+}
+
+// duplicate names are reported on every access of the registry
+mod duplicate {
+    use dyn_derive::*;
+
+    #[dyn_trait(registry)]
+    pub trait Shape {
+        fn unit() -> Self;
+    }
+
+    pub struct Square;
+
+    #[dyn_impl(name = "shape")]
+    impl Shape for Square {
+        fn unit() -> Self {
+            Self
+        }
+    }
+
+    pub struct Circle;
+
+    #[dyn_impl(name = "shape")]
+    impl Shape for Circle {
+        fn unit() -> Self {
+            Self
+        }
+    }
+}
+
+#[test]
+fn main() {
+    let registry = ShapeRegistry::global().unwrap();
+    registry.alias("round", "circle").unwrap();
+    let registry = registry.read();
+    let mut names: Vec<_> = registry.iter().map(|(name, _)| name).collect();
+    names.sort();
+    assert_eq!(names, ["circle", "square", "triangle"]);
+    assert_eq!(registry.get("square").unwrap().unit().area(), 1.0);
+    assert_eq!(registry.get("round").unwrap().unit().area(), std::f64::consts::PI);
+}
+
+#[test]
+fn same_name() {
+    let registry = other::ShapeRegistry::global().unwrap().read();
+    assert_eq!(registry.len(), 1);
+    assert_eq!(registry.get("circle").unwrap().unit().sides(), 0);
+}
+
+#[test]
+fn duplicate() {
+    let error = dyn_std::registry::Error::Duplicate("shape".into());
+    assert_eq!(duplicate::ShapeRegistry::global().unwrap_err(), error);
+    assert_eq!(duplicate::register_shape::<duplicate::Square>("square"), Err(error));
+    // local registries are not affected
+    let mut registry = duplicate::ShapeRegistry::new();
+    registry.register::<duplicate::Square>("shape").unwrap();
+    registry.get("shape").unwrap().unit();
+}
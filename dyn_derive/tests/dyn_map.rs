@@ -0,0 +1,133 @@
+use dyn_derive::*;
+use dyn_std::map::Map1;
+use dyn_std::{Constructor, Instance};
+
+#[derive(DynMap)]
+pub enum Tree<T> {
+    Leaf(T),
+    Node(Box<Tree<T>>, Vec<Tree<T>>),
+}
+
+#[derive(DynMap)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub span: (usize, usize),
+}
+
+#[derive(DynMap)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+#[derive(DynMap)]
+pub struct Wide<A, B, C, D, E, F, G, H> {
+    pub a: A,
+    pub b: B,
+    pub c: C,
+    pub d: D,
+    pub e: E,
+    pub f: F,
+    pub g: G,
+    pub h: Option<H>,
+}
+
+#[dyn_trait]
+pub trait Meta {
+    fn merge(tree: Tree<Self>) -> Self;
+    fn spanned(&self, start: usize) -> Spanned<Self>;
+    fn either(value: Either<Self, i32>) -> Either<i32, Self>;
+    fn wide(&self) -> Wide<(), u8, u16, u32, u64, i8, i16, Self>;
+    fn get(&self) -> i32;
+}
+
+pub struct MetaImpl(i32);
+
+impl Meta for MetaImpl {
+    fn merge(tree: Tree<Self>) -> Self {
+        match tree {
+            Tree::Leaf(leaf) => leaf,
+            Tree::Node(first, rest) => MetaImpl(Self::merge(*first).0 + rest.into_iter().map(|x| Self::merge(x).0).sum::<i32>()),
+        }
+    }
+
+    fn spanned(&self, start: usize) -> Spanned<Self> {
+        Spanned { value: MetaImpl(self.0), span: (start, start + 1) }
+    }
+
+    fn either(value: Either<Self, i32>) -> Either<i32, Self> {
+        match value {
+            Either::Left(value) => Either::Left(value.0),
+            Either::Right(value) => Either::Right(MetaImpl(value)),
+        }
+    }
+
+    fn wide(&self) -> Wide<(), u8, u16, u32, u64, i8, i16, Self> {
+        Wide { a: (), b: 1, c: 2, d: 3, e: 4, f: 5, g: 6, h: Some(MetaImpl(self.0)) }
+    }
+
+    fn get(&self) -> i32 {
+        self.0
+    }
+}
+
+fn leaf(value: i32) -> Tree<Box<dyn MetaInstance>> {
+    Tree::Leaf(Box::new(Instance::new(MetaImpl(value))))
+}
+
+#[test]
+fn main() {
+    let cons: Box<dyn MetaConstructor> = Box::new(Constructor::<MetaImpl>::new());
+    let inst = cons.merge(Tree::Node(Box::new(leaf(1)), vec![Tree::Node(Box::new(leaf(2)), vec![leaf(3)])]));
+    assert_eq!(inst.get(), 6);
+    let spanned = inst.spanned(4);
+    assert_eq!(spanned.value.get(), 6);
+    assert_eq!(spanned.span, (4, 5));
+    let wide = inst.wide();
+    assert_eq!(wide.g, 6);
+    assert_eq!(wide.h.unwrap().get(), 6);
+    let Either::Left(value) = cons.either(Either::Left(spanned.value)) else {
+        panic!("expect left")
+    };
+    assert_eq!(value, 6);
+    let Either::Right(value) = cons.either(Either::Right(7)) else {
+        panic!("expect right")
+    };
+    assert_eq!(value.get(), 7);
+}
+
+#[test]
+fn try_map() {
+    let tree = Tree::Node(Box::new(Tree::Leaf(1)), vec![Tree::Leaf(2), Tree::Leaf(3)]);
+    let mut count = 0;
+    let result = Tree::<String>::try_map(tree, &mut |x: i32| {
+        count += 1;
+        match x {
+            2 => Err(x),
+            _ => Ok(x.to_string()),
+        }
+    });
+    assert!(matches!(result, Err(2)));
+    assert_eq!(count, 2);
+    let tree = Tree::Node(Box::new(Tree::Leaf(1)), vec![Tree::Leaf(3)]);
+    let Ok(Tree::Node(first, _)) = Tree::<i32>::try_map(tree, &mut |x: i32| Ok::<_, ()>(x * 2)) else {
+        panic!("expect node")
+    };
+    assert!(matches!(*first, Tree::Leaf(2)));
+}
+
+#[test]
+fn try_map_array() {
+    let mut count = 0;
+    let result: Result<[String; 3], _> = Map1::try_map([1, 2, 3], &mut |x: i32| {
+        count += 1;
+        match x {
+            2 => Err(x),
+            _ => Ok(x.to_string()),
+        }
+    });
+    assert_eq!(result, Err(2));
+    assert_eq!(count, 2);
+    let result: Result<[String; 3], _> = Map1::try_map([1, 2, 3], &mut |x: i32| Ok::<_, ()>(x.to_string()));
+    assert_eq!(result, Ok([String::from("1"), String::from("2"), String::from("3")]));
+}
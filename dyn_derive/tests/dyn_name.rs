@@ -0,0 +1,32 @@
+use dyn_derive::*;
+use dyn_std::{Constructor, DynName, Instance};
+
+#[dyn_trait(registry)]
+pub trait Shape {
+    fn area(&self) -> f64;
+    fn unit() -> Self;
+}
+
+#[derive(DynName)]
+#[dyn_name = "geo.circle/v1"]
+pub struct Circle(f64);
+
+#[dyn_impl]
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.0 * self.0
+    }
+
+    fn unit() -> Self {
+        Self(1.0)
+    }
+}
+
+#[test]
+fn main() {
+    assert_eq!(Circle::NAME, "geo.circle/v1");
+    assert_eq!(Instance::<Circle>::NAME, "geo.circle/v1");
+    assert_eq!(Constructor::<Circle>::NAME, "geo.circle/v1");
+    let registry = ShapeRegistry::global().unwrap().read();
+    assert_eq!(registry.get("geo.circle/v1").unwrap().unit().area(), std::f64::consts::PI);
+}
@@ -0,0 +1,77 @@
+use std::future::{ready, Future};
+use std::pin::{pin, Pin};
+use std::task::{Context, Poll, Waker};
+
+use dyn_derive::*;
+use dyn_std::{Constructor, Instance};
+use futures_core::Stream;
+
+#[dyn_trait]
+pub trait Meta {
+    fn spawn(&self) -> Pin<Box<dyn Future<Output = Self> + Send>>;
+    fn join(&mut self, other: Pin<&mut dyn Future<Output = Option<Self>>>);
+    fn sum(items: Pin<Box<dyn Stream<Item = Self>>>) -> Pin<Box<dyn Future<Output = Self>>>;
+    fn get(&self) -> i32;
+}
+
+pub struct MetaImpl(i32);
+
+fn block_on<T>(future: impl Future<Output = T>) -> T {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(value) = future.as_mut().poll(&mut cx) {
+            return value
+        }
+    }
+}
+
+struct Iter<I>(I);
+
+impl<I: Iterator + Unpin> Stream for Iter<I> {
+    type Item = I::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<I::Item>> {
+        Poll::Ready(self.0.next())
+    }
+}
+
+impl Meta for MetaImpl {
+    fn spawn(&self) -> Pin<Box<dyn Future<Output = Self> + Send>> {
+        Box::pin(ready(MetaImpl(self.0 + 1)))
+    }
+
+    fn join(&mut self, other: Pin<&mut dyn Future<Output = Option<Self>>>) {
+        if let Some(other) = block_on(other) {
+            self.0 += other.0;
+        }
+    }
+
+    fn sum(mut items: Pin<Box<dyn Stream<Item = Self>>>) -> Pin<Box<dyn Future<Output = Self>>> {
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut sum = 0;
+        while let Poll::Ready(Some(item)) = items.as_mut().poll_next(&mut cx) {
+            sum += item.0;
+        }
+        Box::pin(ready(MetaImpl(sum)))
+    }
+
+    fn get(&self) -> i32 {
+        self.0
+    }
+}
+
+fn new(value: i32) -> Box<dyn MetaInstance> {
+    Box::new(Instance::new(MetaImpl(value)))
+}
+
+#[test]
+fn main() {
+    let cons: Box<dyn MetaConstructor> = Box::new(Constructor::<MetaImpl>::new());
+    let mut inst = block_on(new(42).spawn());
+    assert_eq!(inst.get(), 43);
+    inst.join(pin!(ready(Some(new(1)))));
+    assert_eq!(inst.get(), 44);
+    let sum = block_on(cons.sum(Box::pin(Iter(vec![inst, new(2), new(3)].into_iter()))));
+    assert_eq!(sum.get(), 49);
+}
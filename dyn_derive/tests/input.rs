@@ -1,3 +1,5 @@
+#![allow(clippy::type_complexity, clippy::unnecessary_mut_passed)]
+
 use dyn_derive::*;
 use dyn_std::{Constructor, Instance};
 
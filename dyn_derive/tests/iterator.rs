@@ -0,0 +1,48 @@
+use dyn_derive::*;
+use dyn_std::{Constructor, Instance};
+
+#[dyn_trait]
+pub trait Meta {
+    fn collect(items: Box<dyn Iterator<Item = Self>>) -> Self;
+    fn extend(&mut self, items: &mut dyn DoubleEndedIterator<Item = (Self, i32)>);
+    fn iter(&self) -> Box<dyn ExactSizeIterator<Item = Self> + '_>;
+    fn get(&self) -> i32;
+}
+
+pub struct MetaImpl(Vec<i32>);
+
+impl Meta for MetaImpl {
+    fn collect(items: Box<dyn Iterator<Item = Self>>) -> Self {
+        MetaImpl(items.flat_map(|item| item.0).collect())
+    }
+
+    fn extend(&mut self, items: &mut dyn DoubleEndedIterator<Item = (Self, i32)>) {
+        while let Some((item, n)) = items.next_back() {
+            self.0.extend(item.0.iter().map(|x| x * n));
+        }
+    }
+
+    fn iter(&self) -> Box<dyn ExactSizeIterator<Item = Self> + '_> {
+        Box::new(self.0.iter().map(|x| MetaImpl(vec![*x])))
+    }
+
+    fn get(&self) -> i32 {
+        self.0.iter().sum()
+    }
+}
+
+fn new(values: Vec<i32>) -> Box<dyn MetaInstance> {
+    Box::new(Instance::new(MetaImpl(values)))
+}
+
+#[test]
+fn main() {
+    let cons: Box<dyn MetaConstructor> = Box::new(Constructor::<MetaImpl>::new());
+    let mut inst = cons.collect(Box::new(vec![new(vec![1]), new(vec![2, 3])].into_iter()));
+    assert_eq!(inst.get(), 6);
+    inst.extend(&mut vec![(new(vec![4]), 10), (new(vec![5]), 100)].into_iter());
+    assert_eq!(inst.get(), 546);
+    let iter = inst.iter();
+    assert_eq!(iter.len(), 5);
+    assert_eq!(iter.map(|x| x.get()).collect::<Vec<_>>(), vec![1, 2, 3, 500, 40]);
+}
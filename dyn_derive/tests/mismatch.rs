@@ -0,0 +1,84 @@
+use dyn_derive::*;
+use dyn_std::{Downcast, Instance, Mismatch};
+
+#[derive(Debug, PartialEq)]
+pub struct Error(String);
+
+impl From<Mismatch> for Error {
+    fn from(mismatch: Mismatch) -> Self {
+        Self(mismatch.to_string())
+    }
+}
+
+#[dyn_trait(on_mismatch = "error")]
+pub trait Meta {
+    fn merge(&mut self, other: &Self) -> Result<(), Error>;
+    fn take(&self, other: Box<Self>) -> Option<Self>;
+    fn eq(&self, other: &Self) -> bool;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Count(usize);
+
+impl Meta for Count {
+    fn merge(&mut self, other: &Self) -> Result<(), Error> {
+        self.0 += other.0;
+        Ok(())
+    }
+
+    fn take(&self, other: Box<Self>) -> Option<Self> {
+        Some(*other)
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Flag(bool);
+
+impl Meta for Flag {
+    fn merge(&mut self, other: &Self) -> Result<(), Error> {
+        self.0 |= other.0;
+        Ok(())
+    }
+
+    fn take(&self, other: Box<Self>) -> Option<Self> {
+        Some(*other)
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+#[test]
+fn error() {
+    let mut a: Box<dyn MetaInstance> = Box::new(Instance::new(Count(1)));
+    let b: Box<dyn MetaInstance> = Box::new(Instance::new(Count(2)));
+    let c: Box<dyn MetaInstance> = Box::new(Instance::new(Flag(true)));
+    assert_eq!(a.merge(b.as_ref()), Ok(()));
+    assert_eq!(a.downcast_ref::<Count>(), Some(&Count(3)));
+    let message = format!("expected an instance of `{}`, found `{}`", std::any::type_name::<Count>(), std::any::type_name::<Flag>());
+    assert_eq!(a.merge(c.as_ref()), Err(Error(message)));
+    assert!(a.take(c).is_none());
+    assert!(a.take(b).is_some());
+}
+
+#[test]
+#[should_panic(expected = "expected an instance of `mismatch::Count`, found `mismatch::Flag`")]
+fn panic() {
+    let a: Box<dyn MetaInstance> = Box::new(Instance::new(Count(1)));
+    let c: Box<dyn MetaInstance> = Box::new(Instance::new(Flag(true)));
+    a.eq(c.as_ref());
+}
+
+#[test]
+fn try_downcast() {
+    let a: Box<dyn MetaInstance> = Box::new(Instance::new(Count(1)));
+    assert_eq!(Instance::<Flag>::try_downcast_ref(a.as_ref()), None);
+    assert_eq!(Instance::<Count>::try_downcast_ref(a.as_ref()), Some(&Count(1)));
+    let a = Instance::<Flag>::try_downcast(a).unwrap_err();
+    assert_eq!(Instance::<Count>::try_downcast(a).ok(), Some(Count(1)));
+}
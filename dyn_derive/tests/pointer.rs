@@ -0,0 +1,40 @@
+use dyn_derive::*;
+use dyn_std::{Constructor, Instance};
+
+#[dyn_trait]
+pub trait Meta {
+    fn attach(&mut self, peer: *mut Self);
+    fn null() -> *const Self;
+    fn get(&self) -> i32;
+}
+
+pub struct MetaImpl {
+    value: i32,
+    peer: *mut MetaImpl,
+}
+
+impl Meta for MetaImpl {
+    fn attach(&mut self, peer: *mut Self) {
+        self.peer = peer;
+    }
+
+    fn null() -> *const Self {
+        std::ptr::null()
+    }
+
+    fn get(&self) -> i32 {
+        self.value
+    }
+}
+
+#[test]
+fn main() {
+    let cons: Box<dyn MetaConstructor> = Box::new(Constructor::<MetaImpl>::new());
+    assert!(cons.null().is_null());
+    let mut inst1: Box<dyn MetaInstance> = Box::new(Instance::new(MetaImpl { value: 114, peer: std::ptr::null_mut() }));
+    let mut inst2: Box<dyn MetaInstance> = Box::new(Instance::new(MetaImpl { value: 400, peer: std::ptr::null_mut() }));
+    unsafe { inst1.attach(inst2.as_mut()) };
+    let peer = Instance::<MetaImpl>::downcast_ref(inst1.as_ref()).peer;
+    assert_eq!(peer as *const (), inst2.as_ref() as *const _ as *const ());
+    assert_eq!(unsafe { (*peer).get() }, 400);
+}
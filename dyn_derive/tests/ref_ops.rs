@@ -0,0 +1,96 @@
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Mul};
+
+use dyn_derive::*;
+use dyn_std::Instance;
+
+#[dyn_trait]
+pub trait Meta: Debug + for<'a> AddAssign<&'a Self>
+where
+    for<'a> &'a Self: Add<&'a Self, Output = Self>,
+    for<'a> &'a Self: Mul<&'a Self, Output = Self>,
+{
+    fn get(&self) -> i32;
+}
+
+#[derive(Debug)]
+pub struct MetaImpl(i32);
+
+impl Meta for MetaImpl {
+    fn get(&self) -> i32 {
+        self.0
+    }
+}
+
+impl<'a> Add<&'a MetaImpl> for &'a MetaImpl {
+    type Output = MetaImpl;
+
+    fn add(self, other: &'a MetaImpl) -> MetaImpl {
+        MetaImpl(self.0 + other.0)
+    }
+}
+
+impl<'a> Mul<&'a MetaImpl> for &'a MetaImpl {
+    type Output = MetaImpl;
+
+    fn mul(self, other: &'a MetaImpl) -> MetaImpl {
+        MetaImpl(self.0 * other.0)
+    }
+}
+
+impl<'a> AddAssign<&'a MetaImpl> for MetaImpl {
+    fn add_assign(&mut self, other: &'a MetaImpl) {
+        self.0 += other.0;
+    }
+}
+
+#[test]
+fn main() {
+    let a: Box<dyn MetaInstance> = Box::new(Instance::new(MetaImpl(2)));
+    let b: Box<dyn MetaInstance> = Box::new(Instance::new(MetaImpl(3)));
+    let c = &(&a + &b) * &b;
+    assert_eq!(c.get(), 15);
+    let mut d = c;
+    d += &a;
+    assert_eq!(d.get(), 17);
+    *d += &b;
+    assert_eq!(d.get(), 20);
+    assert_eq!(a.get() + b.get(), 5);
+}
+
+#[derive(Debug)]
+pub struct Zero;
+
+impl Meta for Zero {
+    fn get(&self) -> i32 {
+        0
+    }
+}
+
+impl<'a> Add<&'a Zero> for &'a Zero {
+    type Output = Zero;
+
+    fn add(self, _: &'a Zero) -> Zero {
+        Zero
+    }
+}
+
+impl<'a> Mul<&'a Zero> for &'a Zero {
+    type Output = Zero;
+
+    fn mul(self, _: &'a Zero) -> Zero {
+        Zero
+    }
+}
+
+impl<'a> AddAssign<&'a Zero> for Zero {
+    fn add_assign(&mut self, _: &'a Zero) {}
+}
+
+#[test]
+#[should_panic(expected = "expected an operand of `dyn_std::inst::Instance<ref_ops::MetaImpl>`, found `dyn_std::inst::Instance<ref_ops::Zero>`")]
+fn mismatch() {
+    let a: Box<dyn MetaInstance> = Box::new(Instance::new(MetaImpl(2)));
+    let b: Box<dyn MetaInstance> = Box::new(Instance::new(Zero));
+    let _ = &a + &b;
+}
@@ -0,0 +1,85 @@
+use dyn_derive::*;
+use dyn_std::registry::{Error, Registry};
+
+#[dyn_trait(registry)]
+pub trait Shape {
+    fn area(&self) -> f64;
+    fn unit() -> Self;
+}
+
+pub struct Square(f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+
+    fn unit() -> Self {
+        Self(1.0)
+    }
+}
+
+pub struct Circle(f64);
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.0 * self.0
+    }
+
+    fn unit() -> Self {
+        Self(1.0)
+    }
+}
+
+#[test]
+fn local() {
+    let mut registry = ShapeRegistry::new();
+    registry.register::<Square>("square").unwrap();
+    registry.register::<Circle>("circle").unwrap();
+    registry.alias("rect", "square").unwrap();
+    assert_eq!(registry.register::<Circle>("square"), Err(Error::Duplicate("square".into())));
+    assert_eq!(registry.alias("ellipse", "oval"), Err(Error::NotFound("oval".into())));
+    assert_eq!(registry.len(), 2);
+    assert_eq!(registry.get("rect").unwrap().unit().area(), 1.0);
+    assert!(registry.get("triangle").is_none());
+    let names: Vec<_> = registry.iter().map(|(name, _)| name).collect();
+    assert_eq!(names, ["square", "circle"]);
+}
+
+#[test]
+fn global() {
+    register_shape::<Square>("square").unwrap();
+    std::thread::spawn(|| register_shape::<Circle>("circle").unwrap()).join().unwrap();
+    let registry = ShapeRegistry::global().unwrap().read();
+    assert_eq!(registry.get("circle").unwrap().unit().area(), std::f64::consts::PI);
+    assert_eq!(registry.len(), 2);
+}
+
+// constructors can be registered without `registry`
+#[dyn_trait]
+pub trait Named {
+    fn name(&self) -> &'static str;
+    fn create() -> Self;
+}
+
+pub struct Alice;
+
+impl Named for Alice {
+    fn name(&self) -> &'static str {
+        "alice"
+    }
+
+    fn create() -> Self {
+        Self
+    }
+}
+
+#[test]
+fn without_registry() {
+    let mut registry = Registry::<dyn NamedConstructor>::new();
+    registry.register::<Alice>("alice").unwrap();
+    assert_eq!(registry.get("alice").unwrap().create().name(), "alice");
+    let mut registry = Registry::<dyn NamedConstructor + Send + Sync>::new();
+    registry.register::<Alice>("alice").unwrap();
+    assert_eq!(registry.len(), 1);
+}
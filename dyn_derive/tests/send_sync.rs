@@ -0,0 +1,38 @@
+use std::ops::Add;
+use std::thread;
+
+use dyn_derive::*;
+use dyn_std::Instance;
+
+#[dyn_trait(Send, Sync)]
+pub trait Meta: Clone + PartialEq + Add {
+    fn get(&self) -> i32;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetaImpl(i32);
+
+impl Meta for MetaImpl {
+    fn get(&self) -> i32 {
+        self.0
+    }
+}
+
+impl Add for MetaImpl {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+#[test]
+fn main() {
+    let meta1: Box<dyn MetaInstance> = Box::new(Instance::new(MetaImpl(1)));
+    let meta2 = meta1.clone();
+    let meta3 = thread::spawn(move || meta1 + meta2).join().unwrap();
+    assert_eq!(meta3.get(), 2);
+    assert!(*meta3 == *meta3.clone());
+    let any = thread::spawn(move || meta3.as_any_box_send_sync()).join().unwrap();
+    assert_eq!(any.downcast::<Instance<MetaImpl>>().unwrap().0, MetaImpl(2));
+}
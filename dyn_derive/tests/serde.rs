@@ -0,0 +1,100 @@
+use dyn_derive::*;
+use dyn_std::registry::Registry;
+use serde::{Deserialize, Serialize};
+
+#[dyn_trait(registry)]
+pub trait Shape: serde::Serialize + serde::de::DeserializeOwned {
+    fn area(&self) -> f64;
+}
+
+#[derive(Serialize, Deserialize, DynName)]
+#[dyn_name = "geo.square/v1"]
+pub struct Square {
+    side: f64,
+}
+
+#[dyn_impl]
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.side * self.side
+    }
+}
+
+#[derive(Serialize, Deserialize, DynName)]
+#[dyn_name = "geo.circle/v1"]
+pub struct Circle {
+    radius: f64,
+}
+
+#[dyn_impl]
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+}
+
+#[dyn_trait(registry, tag = "type")]
+pub trait Node: serde::Serialize + for<'de> serde::Deserialize<'de> {
+    fn weight(&self) -> u32;
+}
+
+#[derive(Serialize, Deserialize, DynName)]
+#[dyn_name = "leaf"]
+pub struct Leaf;
+
+#[dyn_impl]
+impl Node for Leaf {
+    fn weight(&self) -> u32 {
+        1
+    }
+}
+
+#[derive(Serialize, Deserialize, DynName)]
+#[dyn_name = "pair"]
+pub struct Pair {
+    left: Box<dyn NodeInstance>,
+    right: Box<dyn NodeInstance>,
+}
+
+#[dyn_impl]
+impl Node for Pair {
+    fn weight(&self) -> u32 {
+        self.left.weight() + self.right.weight()
+    }
+}
+
+#[test]
+fn external() {
+    let shapes: Vec<Box<dyn ShapeInstance>> = vec![
+        Box::new(dyn_std::Instance::new(Square { side: 2.0 })),
+        Box::new(dyn_std::Instance::new(Circle { radius: 1.0 })),
+    ];
+    let json = serde_json::to_string(&shapes).unwrap();
+    assert_eq!(json, r#"[{"geo.square/v1":{"side":2.0}},{"geo.circle/v1":{"radius":1.0}}]"#);
+    let shapes: Vec<Box<dyn ShapeInstance>> = serde_json::from_str(&json).unwrap();
+    assert_eq!(shapes[0].area(), 4.0);
+    assert_eq!(shapes[1].area(), std::f64::consts::PI);
+    let Err(error) = serde_json::from_str::<Box<dyn ShapeInstance>>(r#"{"geo.triangle/v1":{}}"#) else {
+        panic!("expect unknown type name");
+    };
+    assert!(error.to_string().contains("unknown type name `geo.triangle/v1`"));
+}
+
+#[test]
+fn internal() {
+    let leaf = || -> Box<dyn NodeInstance> { Box::new(dyn_std::Instance::new(Leaf)) };
+    let node: Box<dyn NodeInstance> = Box::new(dyn_std::Instance::new(Pair { left: leaf(), right: leaf() }));
+    let json = serde_json::to_string(&node).unwrap();
+    assert_eq!(json, r#"{"type":"pair","left":{"type":"leaf"},"right":{"type":"leaf"}}"#);
+    let node: Box<dyn NodeInstance> = serde_json::from_str(&json).unwrap();
+    assert_eq!(node.weight(), 2);
+}
+
+#[test]
+fn registry() {
+    let mut registry = Registry::<dyn ShapeConstructor>::new();
+    registry.insert("square", Box::new(dyn_std::Constructor::<Square>::new())).unwrap();
+    let mut deserializer = serde_json::Deserializer::from_str(r#"{"square":{"side":3.0}}"#);
+    let shape: Box<dyn ShapeInstance> = dyn_std::serde::deserialize(|name| registry.get(name), None, &mut deserializer).unwrap();
+    assert_eq!(shape.area(), 9.0);
+}
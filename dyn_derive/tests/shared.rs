@@ -0,0 +1,71 @@
+use std::fmt::Debug;
+use std::ops::AddAssign;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use dyn_derive::*;
+use dyn_std::clone::{make_mut_arc, make_mut_rc};
+
+#[dyn_trait]
+pub trait Meta: Debug + Clone + PartialEq + PartialOrd + AddAssign {
+    fn get(&self) -> i32;
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct MetaImpl(i32);
+
+impl MetaInstance for MetaImpl {
+    fn get(&self) -> i32 {
+        self.0
+    }
+}
+
+impl AddAssign for MetaImpl {
+    fn add_assign(&mut self, other: Self) {
+        self.0 += other.0;
+    }
+}
+
+#[test]
+fn compare() {
+    let boxed: Box<dyn MetaInstance> = Box::new(MetaImpl(1));
+    let rc: Rc<dyn MetaInstance> = Rc::new(MetaImpl(1));
+    let arc: Arc<dyn MetaInstance> = Arc::new(MetaImpl(2));
+    let inner: &dyn MetaInstance = &MetaImpl(2);
+    assert!(boxed == rc);
+    assert!(rc == boxed);
+    assert!(boxed != arc);
+    assert!(inner == arc);
+    assert!(arc == inner);
+    assert!(inner != boxed);
+    assert!(boxed < arc);
+    assert!(inner > rc);
+}
+
+#[test]
+fn add_assign() {
+    let mut boxed: Box<dyn MetaInstance> = Box::new(MetaImpl(1));
+    let target: &mut dyn MetaInstance = boxed.as_mut();
+    *target += Box::new(MetaImpl(2)) as Box<dyn MetaInstance>;
+    assert_eq!(boxed.get(), 3);
+    boxed += Box::new(MetaImpl(3)) as Box<dyn MetaInstance>;
+    assert_eq!(boxed.get(), 6);
+}
+
+#[test]
+fn make_mut() {
+    let mut rc1: Rc<dyn MetaInstance> = Rc::new(MetaImpl(1));
+    let rc2 = rc1.clone();
+    *make_mut_rc(&mut rc1) += Box::new(MetaImpl(2)) as Box<dyn MetaInstance>;
+    assert_eq!(rc1.get(), 3);
+    assert_eq!(rc2.get(), 1);
+    let ptr = Rc::as_ptr(&rc1);
+    make_mut_rc(&mut rc1);
+    assert!(std::ptr::addr_eq(ptr, Rc::as_ptr(&rc1)));
+
+    let mut arc1: Arc<dyn MetaInstance> = Arc::new(MetaImpl(1));
+    let arc2 = arc1.clone();
+    *make_mut_arc(&mut arc1) += Box::new(MetaImpl(2)) as Box<dyn MetaInstance>;
+    assert_eq!(arc1.get(), 3);
+    assert_eq!(arc2.get(), 1);
+}
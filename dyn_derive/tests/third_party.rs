@@ -0,0 +1,50 @@
+use arrayvec::ArrayVec;
+use dyn_derive::*;
+use dyn_std::{Constructor, Instance};
+use indexmap::{IndexMap, IndexSet};
+use smallvec::SmallVec;
+
+const CAP: usize = 4;
+
+#[dyn_trait]
+pub trait Meta {
+    fn index_map(items: IndexMap<String, Self>) -> IndexSet<i32>;
+    fn small_vec(items: SmallVec<[Self; 2]>) -> ArrayVec<Self, { CAP }>;
+    fn hash_map(items: hashbrown::HashMap<i32, Self>) -> i32;
+    fn get(&self) -> i32;
+}
+
+pub struct MetaImpl(i32);
+
+impl Meta for MetaImpl {
+    fn index_map(items: IndexMap<String, Self>) -> IndexSet<i32> {
+        items.values().map(|x| x.0).collect()
+    }
+
+    fn small_vec(items: SmallVec<[Self; 2]>) -> ArrayVec<Self, { CAP }> {
+        items.into_iter().rev().collect()
+    }
+
+    fn hash_map(items: hashbrown::HashMap<i32, Self>) -> i32 {
+        items.into_iter().map(|(k, v)| k * v.0).sum()
+    }
+
+    fn get(&self) -> i32 {
+        self.0
+    }
+}
+
+fn new(value: i32) -> Box<dyn MetaInstance> {
+    Box::new(Instance::new(MetaImpl(value)))
+}
+
+#[test]
+fn main() {
+    let cons: Box<dyn MetaConstructor> = Box::new(Constructor::<MetaImpl>::new());
+    let map = IndexMap::from([("b".to_string(), new(2)), ("a".to_string(), new(1))]);
+    assert_eq!(cons.index_map(map).into_iter().collect::<Vec<_>>(), vec![2, 1]);
+    let vec = cons.small_vec(SmallVec::from_iter([new(1), new(2), new(3)]));
+    assert_eq!(vec.iter().map(|x| x.get()).collect::<Vec<_>>(), vec![3, 2, 1]);
+    let map = hashbrown::HashMap::from_iter([(2, new(3)), (4, new(5))]);
+    assert_eq!(cons.hash_map(map), 26);
+}
@@ -0,0 +1,9 @@
+use dyn_derive::*;
+
+#[dyn_adapter]
+pub trait Visitor<#[dynamic] T> {
+    fn visit(&mut self, node: Option<&T>);
+    fn visit_all(&mut self, nodes: Vec<Box<T>>);
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use dyn_derive::*;
+
+#[dyn_adapter]
+pub trait Visitor<#[dynamic] T> {
+    fn visit(self, node: &T);
+    fn create(node: &T) -> Self;
+}
+
+fn main() {}
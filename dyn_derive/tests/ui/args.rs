@@ -0,0 +1,9 @@
+use dyn_derive::*;
+
+#[dyn_trait(instance = DynMeta, name = MetaName)]
+pub trait Meta {}
+
+#[dyn_trait(alias = BoxMeta, alias = MetaBox)]
+pub trait Other {}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use dyn_derive::*;
+
+#[dyn_trait(instance_only)]
+pub trait Meta {
+    fn get(&self) -> i32;
+    fn new() -> Self;
+}
+
+#[dyn_trait(constructor_only, alias = BoxPlugin)]
+pub trait Plugin {
+    fn name() -> String;
+    fn run(&self);
+    fn create() -> Self;
+}
+
+#[dyn_trait(instance_only, constructor_only)]
+pub trait Other {}
+
+fn main() {}
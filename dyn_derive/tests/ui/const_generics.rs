@@ -0,0 +1,8 @@
+use dyn_derive::*;
+
+#[dyn_trait]
+pub trait Meta<const N: usize> {
+    fn get(&self) -> [i32; N];
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+use dyn_derive::*;
+
+#[dyn_trait(registry)]
+pub trait Shape {
+    fn unit() -> Self;
+}
+
+pub struct Circle;
+
+#[dyn_impl]
+impl Shape for Circle {
+    fn unit() -> Self {
+        Circle
+    }
+}
+
+#[dyn_impl(name = "circle")]
+impl Circle {}
+
+pub struct Wrapper<T>(T);
+
+#[dyn_impl(name = "wrapper")]
+impl<T: 'static> Shape for Wrapper<T> {
+    fn unit() -> Self {
+        unimplemented!()
+    }
+}
+
+fn main() {}
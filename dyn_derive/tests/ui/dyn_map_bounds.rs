@@ -0,0 +1,12 @@
+use dyn_derive::*;
+
+#[derive(DynMap)]
+pub struct Wrapper<T: Clone, U>
+where
+    U: Default,
+{
+    value: T,
+    other: U,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use dyn_derive::*;
+
+#[derive(DynMap)]
+pub struct Borrowed<'a, T> {
+    value: &'a T,
+    items: &'a [T],
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use dyn_derive::*;
+
+#[derive(DynMap)]
+pub struct Empty {
+    value: i32,
+}
+
+fn main() {}
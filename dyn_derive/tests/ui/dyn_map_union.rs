@@ -0,0 +1,9 @@
+use dyn_derive::*;
+
+#[derive(DynMap)]
+pub union Either<T: Copy> {
+    left: T,
+    right: u32,
+}
+
+fn main() {}
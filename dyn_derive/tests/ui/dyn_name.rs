@@ -0,0 +1,14 @@
+use dyn_derive::*;
+
+#[derive(DynName)]
+pub struct Square;
+
+#[derive(DynName)]
+#[dyn_name("geo.triangle/v1")]
+pub struct Triangle;
+
+#[derive(DynName)]
+#[dyn_name = "geo.pair/v1"]
+pub struct Pair<T>(T, T);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use dyn_derive::*;
+
+#[dyn_trait]
+pub trait Meta {
+    fn callback(&self) -> fn(Self) -> i32;
+    fn callbacks(&self, f: Option<fn(Self)>);
+}
+
+fn main() {}
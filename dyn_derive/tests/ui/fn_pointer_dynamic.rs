@@ -0,0 +1,9 @@
+use dyn_derive::*;
+
+#[dyn_trait]
+pub trait Meta<#[dynamic] T: ?Sized> {
+    fn visit(&self, f: fn(&Self) -> usize) -> usize;
+    fn get(&self) -> &T;
+}
+
+fn main() {}
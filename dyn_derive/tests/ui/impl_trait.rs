@@ -0,0 +1,8 @@
+use dyn_derive::*;
+
+#[dyn_trait]
+pub trait Meta {
+    fn extend(&mut self, items: impl IntoIterator<Item = Self>);
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use dyn_derive::*;
+
+#[dyn_trait]
+pub trait Meta {
+    fn iter(&self, iter: &dyn Iterator<Item = Self>);
+    fn iter_mut(&mut self) -> &mut dyn Iterator<Item = Self>;
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use dyn_derive::*;
+
+#[dyn_trait]
+pub trait Meta {
+    fn first(&self, other: &[Self]);
+    fn second(&self, other: &&Self);
+    fn third(&self, other: impl Into<Self>);
+}
+
+fn main() {}
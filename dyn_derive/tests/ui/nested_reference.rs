@@ -0,0 +1,8 @@
+use dyn_derive::*;
+
+#[dyn_trait]
+pub trait Meta {
+    fn nested(&self, other: &&Self);
+}
+
+fn main() {}
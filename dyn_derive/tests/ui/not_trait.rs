@@ -0,0 +1,6 @@
+use dyn_derive::*;
+
+#[dyn_trait]
+pub struct Meta;
+
+fn main() {}
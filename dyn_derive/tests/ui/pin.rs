@@ -0,0 +1,13 @@
+#![allow(unused_imports)]
+
+use std::pin::Pin;
+use std::sync::Arc;
+
+use dyn_derive::*;
+
+#[dyn_trait]
+pub trait Meta {
+    fn share(&self, other: Pin<Arc<Self>>);
+}
+
+fn main() {}
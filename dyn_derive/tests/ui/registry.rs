@@ -0,0 +1,13 @@
+use dyn_derive::*;
+
+#[dyn_trait(registry)]
+pub trait Meta<T> {
+    fn new(value: T) -> Self;
+}
+
+#[dyn_trait(instance_only, registry)]
+pub trait Other {
+    fn get(&self) -> i32;
+}
+
+fn main() {}
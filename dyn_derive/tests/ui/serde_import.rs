@@ -0,0 +1,10 @@
+use dyn_derive::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+#[dyn_trait(registry)]
+pub trait Shape: Serialize + DeserializeOwned {
+    fn area(&self) -> f64;
+}
+
+fn main() {}
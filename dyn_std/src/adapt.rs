@@ -0,0 +1,28 @@
+//! Help types for forwarding original traits to dynamic trait objects.
+
+use crate::{Downcast, Instance};
+
+/// Implements an original trait by forwarding to the dynamic trait object behind `P`.
+pub struct Adapter<P>(pub P);
+
+/// Conversions between an implementation `T` and the dynamic (object-safe) trait object.
+pub trait Wrap<T: 'static>: Downcast {
+    fn wrap(value: T) -> Box<Self>;
+    fn wrap_ref(value: &T) -> &Self;
+    fn wrap_mut(value: &mut T) -> &mut Self;
+
+    #[inline]
+    fn unwrap(value: Box<Self>) -> T {
+        Instance::<T>::downcast(value)
+    }
+
+    #[inline]
+    fn unwrap_ref(value: &Self) -> &T {
+        Instance::<T>::downcast_ref(value)
+    }
+
+    #[inline]
+    fn unwrap_mut(value: &mut Self) -> &mut T {
+        Instance::<T>::downcast_mut(value)
+    }
+}
@@ -1,14 +1,32 @@
-use core::any::Any;
+use core::any::{Any, TypeId};
+
+use crate::Instance;
 
 /// This trait is the base trait for most of `dyn_std` traits,
 /// and adds methods to retrieve a `&dyn Any`.
 pub trait Dyn: Any {
+    fn as_dyn(&self) -> &dyn Dyn;
     fn as_any(&self) -> &dyn Any;
     fn as_any_mut(&mut self) -> &mut dyn Any;
     fn as_any_box(self: Box<Self>) -> Box<dyn Any>;
+
+    /// Returns the name of the concrete type, such as `dyn_std::inst::Instance<foo::Bar>`.
+    ///
+    /// Trait objects of `#[dyn_trait]` also implement [`Downcast`], which sees through `Instance<T>` to `T`.
+    fn type_name(&self) -> &'static str;
 }
 
 impl<T: Any> Dyn for T {
+    #[inline(always)]
+    fn type_name(&self) -> &'static str {
+        core::any::type_name::<T>()
+    }
+
+    #[inline(always)]
+    fn as_dyn(&self) -> &dyn Dyn {
+        self
+    }
+
     #[inline(always)]
     fn as_any(&self) -> &dyn Any {
         self
@@ -47,3 +65,79 @@ pub trait Fat<T: ?Sized>: AsRef<T> + Sized {
 }
 
 impl<T: ?Sized, R: AsRef<T>> Fat<T> for R {}
+
+/// [`Dyn`] for types that are [`Send`], and adds a method to retrieve a `Box<dyn Any + Send>`.
+pub trait DynSend: Dyn + Send {
+    fn as_any_box_send(self: Box<Self>) -> Box<dyn Any + Send>;
+}
+
+impl<T: Any + Send> DynSend for T {
+    #[inline(always)]
+    fn as_any_box_send(self: Box<Self>) -> Box<dyn Any + Send> {
+        self
+    }
+}
+
+/// [`Dyn`] for types that are [`Send`] and [`Sync`], and adds a method to retrieve a `Box<dyn Any + Send + Sync>`.
+pub trait DynSendSync: DynSend + Sync {
+    fn as_any_box_send_sync(self: Box<Self>) -> Box<dyn Any + Send + Sync>;
+}
+
+impl<T: Any + Send + Sync> DynSendSync for T {
+    #[inline(always)]
+    fn as_any_box_send_sync(self: Box<Self>) -> Box<dyn Any + Send + Sync> {
+        self
+    }
+}
+
+/// Type identity and downcasting of trait objects, which see through [`Instance<T>`] to `T`.
+///
+/// Implemented by `#[dyn_trait]` for `dyn FooInstance`, `dyn FooInstance + Send` and `dyn FooInstance + Send + Sync`.
+pub trait Downcast: Dyn {
+    /// Returns the name of the implementation.
+    fn type_name_of_inner(&self) -> &'static str;
+
+    /// Returns the `TypeId` of the implementation.
+    fn type_id_of_inner(&self) -> TypeId;
+
+    /// Returns `true` if the implementation is `T`.
+    #[inline]
+    fn is<T: 'static>(&self) -> bool {
+        self.type_id_of_inner() == TypeId::of::<T>()
+    }
+
+    /// Returns a reference to the implementation if it is `T`.
+    #[inline]
+    fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        let any = Dyn::as_any(self);
+        match any.downcast_ref::<Instance<T>>() {
+            Some(value) => Some(&value.0),
+            None => any.downcast_ref::<T>(),
+        }
+    }
+
+    /// Returns a mutable reference to the implementation if it is `T`.
+    #[inline]
+    fn downcast_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        if !self.is::<T>() {
+            return None
+        }
+        let any = Dyn::as_any_mut(self);
+        match any.is::<Instance<T>>() {
+            true => any.downcast_mut::<Instance<T>>().map(|value| &mut value.0),
+            false => any.downcast_mut::<T>(),
+        }
+    }
+
+    /// Returns the implementation if it is `T`, or gives the box back otherwise.
+    #[inline]
+    fn downcast<T: 'static>(self: Box<Self>) -> Result<T, Box<Self>> {
+        if !self.is::<T>() {
+            return Err(self)
+        }
+        match Dyn::as_any_box(self).downcast::<Instance<T>>() {
+            Ok(value) => Ok(value.0),
+            Err(any) => Ok(*any.downcast::<T>().unwrap()),
+        }
+    }
+}
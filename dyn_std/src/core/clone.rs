@@ -1,4 +1,7 @@
-use crate::Instance;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::{Fat, Instance};
 
 /// Dynamic (object-safe) version of [`Clone`](https://doc.rust-lang.org/nightly/core/clone/trait.Clone.html).
 pub trait Clone {
@@ -32,3 +35,23 @@ impl<T: core::clone::Clone> core::clone::Clone for Instance<T> {
         Self::new(self.0.clone())
     }
 }
+
+/// Makes a mutable reference into the given `Rc` like [`Rc::make_mut`], which also works for trait objects.
+///
+/// If there are other `Rc` or `Weak` pointers to the same allocation, the value is cloned by [`Clone::dyn_clone`] first.
+pub fn make_mut_rc<T: ?Sized + Clone>(this: &mut Rc<T>) -> &mut T {
+    if Rc::get_mut(this).is_none() {
+        *this = Rc::from(this.clone().to_box(T::dyn_clone));
+    }
+    Rc::get_mut(this).unwrap()
+}
+
+/// Makes a mutable reference into the given `Arc` like [`Arc::make_mut`], which also works for trait objects.
+///
+/// If there are other `Arc` or `Weak` pointers to the same allocation, the value is cloned by [`Clone::dyn_clone`] first.
+pub fn make_mut_arc<T: ?Sized + Clone>(this: &mut Arc<T>) -> &mut T {
+    if Arc::get_mut(this).is_none() {
+        *this = Arc::from(this.clone().to_box(T::dyn_clone));
+    }
+    Arc::get_mut(this).unwrap()
+}
@@ -10,7 +10,7 @@ pub trait PartialEq {
 impl<T: Dyn + core::cmp::PartialEq> PartialEq for T {
     #[inline]
     fn dyn_eq(&self, other: &dyn Any) -> bool {
-        other.downcast_ref::<Self>().map_or(false, |other| self.eq(other))
+        other.downcast_ref::<Self>().is_some_and(|other| self.eq(other))
     }
 }
 
@@ -36,7 +36,7 @@ pub trait PartialOrd: Dyn {
 impl<T: Dyn + core::cmp::PartialOrd> PartialOrd for T {
     #[inline]
     fn dyn_partial_cmp(&self, other: &dyn Any) -> Option<core::cmp::Ordering> {
-        other.downcast_ref::<Self>().map_or(None, |other| self.partial_cmp(other))
+        other.downcast_ref::<Self>().and_then(|other| self.partial_cmp(other))
     }
 }
 
@@ -75,6 +75,73 @@ macro_rules! assign {
     };
 }
 
+/// Downcasts the right operand of a reference operator, which is of the same type as the left one.
+#[inline]
+fn downcast_operand<T: 'static>(other: &dyn Dyn) -> &T {
+    match other.as_any().downcast_ref::<T>() {
+        Some(other) => other,
+        None => panic!("expected an operand of `{}`, found `{}`", core::any::type_name::<T>(), other.type_name()),
+    }
+}
+
+macro_rules! binary_ref {
+    ($trait:ident, $ref_trait:ident, $method:ident, $original:ident, $doc:tt) => {
+        #[doc = $doc]
+        pub trait $ref_trait {
+            fn $method(&self, other: &dyn Dyn) -> *mut ();
+        }
+
+        impl<T: Dyn> $ref_trait for T
+        where
+            for<'a> &'a T: core::ops::$trait<&'a T, Output = T>,
+        {
+            #[inline]
+            fn $method(&self, other: &dyn Dyn) -> *mut () {
+                let other = downcast_operand::<Self>(other);
+                Box::leak(Box::from(core::ops::$trait::$original(self, other))) as *const T as *mut ()
+            }
+        }
+
+        impl<'a, T> core::ops::$trait<&'a Instance<T>> for &'a Instance<T>
+        where
+            &'a T: core::ops::$trait<&'a T, Output = T>,
+        {
+            type Output = Instance<T>;
+            #[inline]
+            fn $original(self, other: &'a Instance<T>) -> Instance<T> {
+                Instance::new(core::ops::$trait::$original(&self.0, &other.0))
+            }
+        }
+    };
+}
+
+macro_rules! assign_ref {
+    ($trait:ident, $ref_trait:ident, $method:ident, $original:ident, $doc:tt) => {
+        #[doc = $doc]
+        pub trait $ref_trait {
+            fn $method(&mut self, other: &dyn Dyn);
+        }
+
+        impl<T: Dyn> $ref_trait for T
+        where
+            for<'a> T: core::ops::$trait<&'a T>,
+        {
+            #[inline]
+            fn $method(&mut self, other: &dyn Dyn) {
+                let other = downcast_operand::<Self>(other);
+                self.$original(other);
+            }
+        }
+
+        impl<'a, T: core::ops::$trait<&'a T>> core::ops::$trait<&'a Instance<T>> for Instance<T> {
+            #[inline]
+            fn $original(&mut self, other: &'a Instance<T>) {
+                self.0.$original(&other.0)
+            }
+        }
+    };
+}
+
 unary!(Neg, dyn_neg, neg, "Dynamic (object-safe) version of [`Neg`](https://doc.rust-lang.org/nightly/core/ops/trait.Neg.html)");
 unary!(Not, dyn_not, not, "Dynamic (object-safe) version of [`Not`](https://doc.rust-lang.org/nightly/core/ops/trait.Not.html)");
 
@@ -99,3 +166,25 @@ assign!(BitOrAssign, dyn_bitor_assign, bitor_assign, "Dynamic (object-safe) vers
 assign!(BitXorAssign, dyn_bitxor_assign, bitxor_assign, "Dynamic (object-safe) version of [`BitXorAssign`](https://doc.rust-lang.org/nightly/core/ops/trait.BitXorAssign.html)");
 assign!(ShlAssign, dyn_shl_assign, shl_assign, "Dynamic (object-safe) version of [`ShlAssign`](https://doc.rust-lang.org/nightly/core/ops/trait.ShlAssign.html)");
 assign!(ShrAssign, dyn_shr_assign, shr_assign, "Dynamic (object-safe) version of [`ShrAssign`](https://doc.rust-lang.org/nightly/core/ops/trait.ShrAssign.html)");
+
+binary_ref!(Add, AddRef, dyn_add_ref, add, "Dynamic (object-safe) version of [`Add`](https://doc.rust-lang.org/nightly/core/ops/trait.Add.html) on references");
+binary_ref!(Sub, SubRef, dyn_sub_ref, sub, "Dynamic (object-safe) version of [`Sub`](https://doc.rust-lang.org/nightly/core/ops/trait.Sub.html) on references");
+binary_ref!(Mul, MulRef, dyn_mul_ref, mul, "Dynamic (object-safe) version of [`Mul`](https://doc.rust-lang.org/nightly/core/ops/trait.Mul.html) on references");
+binary_ref!(Div, DivRef, dyn_div_ref, div, "Dynamic (object-safe) version of [`Div`](https://doc.rust-lang.org/nightly/core/ops/trait.Div.html) on references");
+binary_ref!(Rem, RemRef, dyn_rem_ref, rem, "Dynamic (object-safe) version of [`Rem`](https://doc.rust-lang.org/nightly/core/ops/trait.Rem.html) on references");
+binary_ref!(BitAnd, BitAndRef, dyn_bitand_ref, bitand, "Dynamic (object-safe) version of [`BitAnd`](https://doc.rust-lang.org/nightly/core/ops/trait.BitAnd.html) on references");
+binary_ref!(BitOr, BitOrRef, dyn_bitor_ref, bitor, "Dynamic (object-safe) version of [`BitOr`](https://doc.rust-lang.org/nightly/core/ops/trait.BitOr.html) on references");
+binary_ref!(BitXor, BitXorRef, dyn_bitxor_ref, bitxor, "Dynamic (object-safe) version of [`BitXor`](https://doc.rust-lang.org/nightly/core/ops/trait.BitXor.html) on references");
+binary_ref!(Shl, ShlRef, dyn_shl_ref, shl, "Dynamic (object-safe) version of [`Shl`](https://doc.rust-lang.org/nightly/core/ops/trait.Shl.html) on references");
+binary_ref!(Shr, ShrRef, dyn_shr_ref, shr, "Dynamic (object-safe) version of [`Shr`](https://doc.rust-lang.org/nightly/core/ops/trait.Shr.html) on references");
+
+assign_ref!(AddAssign, AddAssignRef, dyn_add_assign_ref, add_assign, "Dynamic (object-safe) version of [`AddAssign`](https://doc.rust-lang.org/nightly/core/ops/trait.AddAssign.html) on references");
+assign_ref!(SubAssign, SubAssignRef, dyn_sub_assign_ref, sub_assign, "Dynamic (object-safe) version of [`SubAssign`](https://doc.rust-lang.org/nightly/core/ops/trait.SubAssign.html) on references");
+assign_ref!(MulAssign, MulAssignRef, dyn_mul_assign_ref, mul_assign, "Dynamic (object-safe) version of [`MulAssign`](https://doc.rust-lang.org/nightly/core/ops/trait.MulAssign.html) on references");
+assign_ref!(DivAssign, DivAssignRef, dyn_div_assign_ref, div_assign, "Dynamic (object-safe) version of [`DivAssign`](https://doc.rust-lang.org/nightly/core/ops/trait.DivAssign.html) on references");
+assign_ref!(RemAssign, RemAssignRef, dyn_rem_assign_ref, rem_assign, "Dynamic (object-safe) version of [`RemAssign`](https://doc.rust-lang.org/nightly/core/ops/trait.RemAssign.html) on references");
+assign_ref!(BitAndAssign, BitAndAssignRef, dyn_bitand_assign_ref, bitand_assign, "Dynamic (object-safe) version of [`BitAndAssign`](https://doc.rust-lang.org/nightly/core/ops/trait.BitAndAssign.html) on references");
+assign_ref!(BitOrAssign, BitOrAssignRef, dyn_bitor_assign_ref, bitor_assign, "Dynamic (object-safe) version of [`BitOrAssign`](https://doc.rust-lang.org/nightly/core/ops/trait.BitOrAssign.html) on references");
+assign_ref!(BitXorAssign, BitXorAssignRef, dyn_bitxor_assign_ref, bitxor_assign, "Dynamic (object-safe) version of [`BitXorAssign`](https://doc.rust-lang.org/nightly/core/ops/trait.BitXorAssign.html) on references");
+assign_ref!(ShlAssign, ShlAssignRef, dyn_shl_assign_ref, shl_assign, "Dynamic (object-safe) version of [`ShlAssign`](https://doc.rust-lang.org/nightly/core/ops/trait.ShlAssign.html) on references");
+assign_ref!(ShrAssign, ShrAssignRef, dyn_shr_assign_ref, shr_assign, "Dynamic (object-safe) version of [`ShrAssign`](https://doc.rust-lang.org/nightly/core/ops/trait.ShrAssign.html) on references");
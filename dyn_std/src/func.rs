@@ -0,0 +1,89 @@
+//! Help functions for passing trait objects as function pointers.
+//!
+//! A function pointer cannot capture its environment, so the trait object it stands for
+//! is registered on a thread-local stack by [`enter`], and looked up by [`with`] when called.
+//! Each call site registers under its own key type `K`, so that function pointers
+//! of the same signature passed by different call sites cannot be confused.
+//! As a type declared in a generic function is shared by all its instantiations,
+//! the key also carries the generics of the call site by [`Key`].
+
+use core::any::TypeId;
+use core::cell::RefCell;
+use core::marker::PhantomData;
+
+/// Key of a call site `K` in a generic function, such as `Key<(Site, T)>`.
+pub struct Key<K: ?Sized>(PhantomData<fn() -> K>);
+
+struct Entry {
+    key: TypeId,
+    ptr: *const (),
+    // whether the trait object is being called, so that it is skipped by nested lookups
+    busy: bool,
+}
+
+thread_local! {
+    static SCOPES: RefCell<Vec<Entry>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Guard returned by [`enter`], which unregisters the trait object when dropped.
+pub struct Scope<'a>(PhantomData<&'a ()>);
+
+impl Drop for Scope<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        SCOPES.with(|scopes| scopes.borrow_mut().pop());
+    }
+}
+
+struct Busy(usize);
+
+impl Drop for Busy {
+    #[inline]
+    fn drop(&mut self) {
+        SCOPES.with(|scopes| {
+            if let Some(entry) = scopes.borrow_mut().get_mut(self.0) {
+                entry.busy = false;
+            }
+        });
+    }
+}
+
+/// Registers `f` under the key `K` on the current thread, until the guard is dropped.
+///
+/// # Safety
+///
+/// - Every [`with`] under the key `K` must take `F` as the same type as `G`, except for lifetimes.
+/// - The guard must be dropped before `f` is, and in the reverse order of registration.
+///   In particular, it must not be leaked, e.g. by [`mem::forget`](core::mem::forget),
+///   as the stack would then refer to a dangling trait object.
+#[inline]
+pub unsafe fn enter<'a, K: 'static, G: ?Sized>(f: &'a &'a G) -> Scope<'a> {
+    let ptr = f as *const &G as *const ();
+    SCOPES.with(|scopes| scopes.borrow_mut().push(Entry { key: TypeId::of::<K>(), ptr, busy: false }));
+    Scope(PhantomData)
+}
+
+/// Calls `g` with the innermost `F` registered by [`enter`] under the key `K`.
+///
+/// While `g` is running, the trait object is skipped by lookups under the same key,
+/// so that a function pointer passed back to its own call site refers to the outer trait object,
+/// instead of calling itself forever.
+///
+/// A function pointer called after the method it was passed to has returned refers to
+/// whichever trait object is registered under the same key at that time, if any.
+///
+/// # Panics
+///
+/// Panics if no `F` is registered under the key `K`, e.g. when a function pointer is called
+/// after the method it was passed to has returned, or from another thread.
+#[inline]
+pub fn with<K: 'static, F: ?Sized, R>(g: impl FnOnce(&F) -> R) -> R {
+    let entry = SCOPES.with(|scopes| {
+        let mut scopes = scopes.borrow_mut();
+        let (index, entry) = scopes.iter_mut().enumerate().rev().find(|(_, entry)| entry.key == TypeId::of::<K>() && !entry.busy)?;
+        entry.busy = true;
+        Some((Busy(index), entry.ptr))
+    });
+    let (_busy, ptr) = entry.expect("function pointer called outside of its scope");
+    g(unsafe { *(ptr as *const &F) })
+}
@@ -0,0 +1,44 @@
+//! Help types for mapping futures and streams lazily.
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// Future or stream which maps the values of `P` with `F` as they are polled.
+pub struct Map<P, F> {
+    inner: P,
+    f: F,
+}
+
+impl<P, F> Map<P, F> {
+    #[inline]
+    pub fn new(inner: P, f: F) -> Self {
+        Self { inner, f }
+    }
+}
+
+impl<P: Future + Unpin, F: FnMut(P::Output) -> T + Unpin, T> Future for Map<P, F> {
+    type Output = T;
+
+    #[inline]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let this = self.get_mut();
+        Pin::new(&mut this.inner).poll(cx).map(&mut this.f)
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<P: futures_core::Stream + Unpin, F: FnMut(P::Item) -> T + Unpin, T> futures_core::Stream for Map<P, F> {
+    type Item = T;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let this = self.get_mut();
+        Pin::new(&mut this.inner).poll_next(cx).map(|item| item.map(&mut this.f))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
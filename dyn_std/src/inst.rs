@@ -1,37 +1,147 @@
 use core::marker::PhantomData;
 
-use crate::Dyn;
+use crate::{Downcast, Dyn};
 
+/// A stable name of a type, which stays the same across builds unlike [`core::any::type_name`].
+///
+/// Derived by `#[derive(DynName)]` with `#[dyn_name = "..."]`. Names are checked to be unique within a registry as implementations are registered.
+pub trait DynName {
+    const NAME: &'static str;
+}
+
+#[repr(transparent)]
 pub struct Instance<T>(pub T);
 
 impl<T> Instance<T> {
     pub fn new(value: T) -> Self {
         Self(value)
     }
+
+    #[inline]
+    pub fn from_ref(value: &T) -> &Self {
+        unsafe { &*(value as *const T as *const Self) }
+    }
+
+    #[inline]
+    pub fn from_mut(value: &mut T) -> &mut Self {
+        unsafe { &mut *(value as *mut T as *mut Self) }
+    }
 }
 
 impl<T: 'static> Instance<T> {
+    /// Returns the inner value if `v` is an `Instance<T>`, or `None` otherwise.
+    #[inline]
+    pub fn try_downcast_ref<D: Dyn + ?Sized>(v: &D) -> Option<&T> {
+        v.as_any().downcast_ref::<Self>().map(|v| &v.0)
+    }
+
+    /// Returns the inner value if `v` is an `Instance<T>`, or `None` otherwise.
+    #[inline]
+    pub fn try_downcast_mut<D: Dyn + ?Sized>(v: &mut D) -> Option<&mut T> {
+        v.as_any_mut().downcast_mut::<Self>().map(|v| &mut v.0)
+    }
+
+    /// Returns the inner value if `v` is an `Instance<T>`, or gives the box back otherwise.
     #[inline]
-    pub fn downcast_ref<D: Dyn + ?Sized>(v: &D) -> &T {
-        &v.as_any().downcast_ref::<Self>().unwrap().0
+    pub fn try_downcast<D: Dyn + ?Sized>(v: Box<D>) -> Result<T, Box<D>> {
+        if !(*v).as_any().is::<Self>() {
+            return Err(v)
+        }
+        Ok(v.as_any_box().downcast::<Self>().unwrap().0)
     }
 
+    /// Checks that `v` is an `Instance<T>`.
     #[inline]
-    pub fn downcast_mut<D: Dyn + ?Sized>(v: &mut D) -> &mut T {
-        &mut v.as_any_mut().downcast_mut::<Self>().unwrap().0
+    pub fn check<D: Downcast + ?Sized>(v: &D) -> Result<(), Mismatch> {
+        match v.as_any().is::<Self>() {
+            true => Ok(()),
+            false => Err(Mismatch::new::<T, D>(v)),
+        }
     }
 
+    /// # Panics
+    ///
+    /// Panics if `v` is not an `Instance<T>`.
     #[inline]
-    pub fn downcast<D: Dyn + ?Sized>(v: Box<D>) -> T {
-        v.as_any_box().downcast::<Self>().unwrap().0
+    pub fn downcast_ref<D: Downcast + ?Sized>(v: &D) -> &T {
+        match Self::try_downcast_ref(v) {
+            Some(v) => v,
+            None => Mismatch::new::<T, D>(v).panic(),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `v` is not an `Instance<T>`.
+    #[inline]
+    pub fn downcast_mut<D: Downcast + ?Sized>(v: &mut D) -> &mut T {
+        if let Err(mismatch) = Self::check(v) {
+            mismatch.panic()
+        }
+        Self::try_downcast_mut(v).unwrap()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `v` is not an `Instance<T>`.
+    #[inline]
+    pub fn downcast<D: Downcast + ?Sized>(v: Box<D>) -> T {
+        match Self::try_downcast(v) {
+            Ok(v) => v,
+            Err(v) => Mismatch::new::<T, D>(&*v).panic(),
+        }
     }
 }
 
+/// The error of a downcast to an implementation other than that of the value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    expected: &'static str,
+    actual: &'static str,
+}
+
+impl Mismatch {
+    fn new<T, D: Downcast + ?Sized>(v: &D) -> Self {
+        Self {
+            expected: core::any::type_name::<T>(),
+            actual: v.type_name_of_inner(),
+        }
+    }
+
+    /// Returns the name of the expected type.
+    pub fn expected(&self) -> &'static str {
+        self.expected
+    }
+
+    /// Returns the name of the actual type.
+    pub fn actual(&self) -> &'static str {
+        self.actual
+    }
+
+    #[cold]
+    fn panic(&self) -> ! {
+        panic!("{}", self)
+    }
+}
+
+impl core::fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "expected an instance of `{}`, found `{}`", self.expected, self.actual)
+    }
+}
+
+impl std::error::Error for Mismatch {}
+
+impl<T: DynName> DynName for Instance<T> {
+    const NAME: &'static str = T::NAME;
+}
+
+// `fn() -> T` keeps constructors `Send` and `Sync`, as no value of `T` is held.
 #[doc(hidden)]
-pub struct Constructor<T>(PhantomData<T>);
+pub struct Constructor<T>(pub(crate) PhantomData<fn() -> T>);
 
 impl<T> Constructor<T> {
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self(PhantomData)
     }
 }
@@ -41,3 +151,36 @@ impl<T> Default for Constructor<T> {
         Self::new()
     }
 }
+
+impl<T> Clone for Constructor<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Constructor<T> {}
+
+impl<T> core::fmt::Debug for Constructor<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Constructor<{}>", core::any::type_name::<T>())
+    }
+}
+
+// constructors of the same type are identical
+impl<T> PartialEq for Constructor<T> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<T> Eq for Constructor<T> {}
+
+impl<T: 'static> core::hash::Hash for Constructor<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::any::TypeId::of::<T>().hash(state)
+    }
+}
+
+impl<T: DynName> DynName for Constructor<T> {
+    const NAME: &'static str = T::NAME;
+}
@@ -4,9 +4,15 @@
 
 mod core;
 
+pub mod adapt;
 pub mod any;
+pub mod func;
+pub mod future;
 pub mod inst;
 pub mod map;
+pub mod registry;
+#[cfg(feature = "serde")]
+pub mod serde;
 
 pub use any::*;
 pub use core::*;
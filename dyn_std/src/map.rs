@@ -1,13 +1,20 @@
-use std::collections::{BTreeMap, HashMap, HashSet};
-use std::hash::Hash;
+//! Help traits for unsized coercion.
+//!
+//! Mapping functions are passed by mutable reference, so that recursive containers
+//! can pass them down without instantiating a new closure type at each level.
 
-/// Help traits for unsized coercion.
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::hash::{BuildHasher, Hash};
+use std::sync::{Mutex, PoisonError, RwLock};
 
 macro_rules! map_trait {
     ($n:ident; $($s:ident),*; $($t:ident),*; $($f:ident),*) => {
-        pub trait $n<$($t),*> {
+        #[allow(clippy::too_many_arguments)]
+        pub trait $n<$($t),*>: Sized {
             type Input<$($s),*>;
-            fn map<$($s),*>(value: Self::Input<$($s),*>, $($f: fn($s) -> $t),*) -> Self;
+            fn map<$($s),*>(value: Self::Input<$($s),*>, $($f: &mut impl FnMut($s) -> $t),*) -> Self;
+            fn try_map<$($s,)* E>(value: Self::Input<$($s),*>, $($f: &mut impl FnMut($s) -> Result<$t, E>),*) -> Result<Self, E>;
         }
     };
 }
@@ -18,59 +25,209 @@ map_trait!(Map3; S1, S2, S3; T1, T2, T3; f1, f2, f3);
 map_trait!(Map4; S1, S2, S3, S4; T1, T2, T3, T4; f1, f2, f3, f4);
 map_trait!(Map5; S1, S2, S3, S4, S5; T1, T2, T3, T4, T5; f1, f2, f3, f4, f5);
 map_trait!(Map6; S1, S2, S3, S4, S5, S6; T1, T2, T3, T4, T5, T6; f1, f2, f3, f4, f5, f6);
+map_trait!(Map7; S1, S2, S3, S4, S5, S6, S7; T1, T2, T3, T4, T5, T6, T7; f1, f2, f3, f4, f5, f6, f7);
+map_trait!(Map8; S1, S2, S3, S4, S5, S6, S7, S8; T1, T2, T3, T4, T5, T6, T7, T8; f1, f2, f3, f4, f5, f6, f7, f8);
+map_trait!(Map9; S1, S2, S3, S4, S5, S6, S7, S8, S9; T1, T2, T3, T4, T5, T6, T7, T8, T9; f1, f2, f3, f4, f5, f6, f7, f8, f9);
+map_trait!(Map10; S1, S2, S3, S4, S5, S6, S7, S8, S9, S10; T1, T2, T3, T4, T5, T6, T7, T8, T9, T10; f1, f2, f3, f4, f5, f6, f7, f8, f9, f10);
+map_trait!(Map11; S1, S2, S3, S4, S5, S6, S7, S8, S9, S10, S11; T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11; f1, f2, f3, f4, f5, f6, f7, f8, f9, f10, f11);
+map_trait!(Map12; S1, S2, S3, S4, S5, S6, S7, S8, S9, S10, S11, S12; T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12; f1, f2, f3, f4, f5, f6, f7, f8, f9, f10, f11, f12);
+
+/// The maximum number of type arguments supported by the `MapN` traits.
+pub const MAX_ARITY: usize = 12;
 
 impl<T1, const N: usize> Map1<T1> for [T1; N] {
     type Input<S1> = [S1; N];
     #[inline]
-    fn map<S1>(value: Self::Input<S1>, f1: fn(S1) -> T1) -> Self {
+    fn map<S1>(value: Self::Input<S1>, f1: &mut impl FnMut(S1) -> T1) -> Self {
         value.map(f1)
     }
+    #[inline]
+    fn try_map<S1, E>(value: Self::Input<S1>, f1: &mut impl FnMut(S1) -> Result<T1, E>) -> Result<Self, E> {
+        // elements after the first error are dropped without being mapped
+        let mut error = None;
+        let array = value.map(|value| {
+            if error.is_some() {
+                return None
+            }
+            f1(value).map_err(|e| error = Some(e)).ok()
+        });
+        match error {
+            Some(error) => Err(error),
+            None => Ok(array.map(Option::unwrap)),
+        }
+    }
 }
 
 impl<T1> Map1<T1> for Option<T1> {
     type Input<S1> = Option<S1>;
     #[inline]
-    fn map<S1>(value: Self::Input<S1>, f1: fn(S1) -> T1) -> Self {
+    fn map<S1>(value: Self::Input<S1>, f1: &mut impl FnMut(S1) -> T1) -> Self {
         value.map(f1)
     }
-}
-
-impl<T1> Map1<T1> for Vec<T1> {
-    type Input<S1> = Vec<S1>;
     #[inline]
-    fn map<S1>(value: Self::Input<S1>, f1: fn(S1) -> T1) -> Self {
-        value.into_iter().map(f1).collect()
+    fn try_map<S1, E>(value: Self::Input<S1>, f1: &mut impl FnMut(S1) -> Result<T1, E>) -> Result<Self, E> {
+        value.map(f1).transpose()
     }
 }
 
-impl<T1: Eq + Hash> Map1<T1> for HashSet<T1> {
-    type Input<S1> = HashSet<S1>;
+macro_rules! map_collection {
+    ($($($ty:ident)::+ $(: $bound:path)?),*) => {
+        $(
+            impl<T1 $(: $bound)?> Map1<T1> for $($ty)::+<T1> {
+                type Input<S1> = $($ty)::+<S1>;
+                #[inline]
+                fn map<S1>(value: Self::Input<S1>, f1: &mut impl FnMut(S1) -> T1) -> Self {
+                    value.into_iter().map(f1).collect()
+                }
+                #[inline]
+                fn try_map<S1, E>(value: Self::Input<S1>, f1: &mut impl FnMut(S1) -> Result<T1, E>) -> Result<Self, E> {
+                    value.into_iter().map(f1).collect()
+                }
+            }
+        )*
+    };
+}
+
+map_collection!(Vec, VecDeque, LinkedList, BTreeSet: Ord, BinaryHeap: Ord);
+
+impl<T1> Map1<T1> for Box<[T1]> {
+    type Input<S1> = Box<[S1]>;
     #[inline]
-    fn map<S1>(value: Self::Input<S1>, f1: fn(S1) -> T1) -> Self {
-        value.into_iter().map(f1).collect()
+    fn map<S1>(value: Self::Input<S1>, f1: &mut impl FnMut(S1) -> T1) -> Self {
+        value.into_vec().into_iter().map(f1).collect()
     }
+    #[inline]
+    fn try_map<S1, E>(value: Self::Input<S1>, f1: &mut impl FnMut(S1) -> Result<T1, E>) -> Result<Self, E> {
+        value.into_vec().into_iter().map(f1).collect()
+    }
+}
+
+macro_rules! map_cell {
+    ($($ty:ident => |$value:ident| $inner:expr),*) => {
+        $(
+            impl<T1> Map1<T1> for $ty<T1> {
+                type Input<S1> = $ty<S1>;
+                #[inline]
+                fn map<S1>($value: Self::Input<S1>, f1: &mut impl FnMut(S1) -> T1) -> Self {
+                    $ty::new(f1($inner))
+                }
+                #[inline]
+                fn try_map<S1, E>($value: Self::Input<S1>, f1: &mut impl FnMut(S1) -> Result<T1, E>) -> Result<Self, E> {
+                    Ok($ty::new(f1($inner)?))
+                }
+            }
+        )*
+    };
+}
+
+map_cell! {
+    Cell => |value| value.into_inner(),
+    RefCell => |value| value.into_inner(),
+    Mutex => |value| value.into_inner().unwrap_or_else(PoisonError::into_inner),
+    RwLock => |value| value.into_inner().unwrap_or_else(PoisonError::into_inner)
 }
 
+// `Cow<'a, S1>` is not implemented, since it requires `S1: ToOwned`,
+// which cannot be expressed by the unbounded `Input<S1>`.
+
 impl<T1, T2> Map2<T1, T2> for Result<T1, T2> {
     type Input<S1, S2> = Result<S1, S2>;
     #[inline]
-    fn map<S1, S2>(value: Self::Input<S1, S2>, f1: fn(S1) -> T1, f2: fn(S2) -> T2) -> Self {
+    fn map<S1, S2>(value: Self::Input<S1, S2>, f1: &mut impl FnMut(S1) -> T1, f2: &mut impl FnMut(S2) -> T2) -> Self {
         value.map(f1).map_err(f2)
     }
-}
-
-impl<T1: Eq + Hash, T2> Map2<T1, T2> for HashMap<T1, T2> {
-    type Input<S1, S2> = HashMap<S1, S2>;
     #[inline]
-    fn map<S1, S2>(value: Self::Input<S1, S2>, f1: fn(S1) -> T1, f2: fn(S2) -> T2) -> Self {
-        value.into_iter().map(|(k, v)| (f1(k), f2(v))).collect()
+    fn try_map<S1, S2, E>(value: Self::Input<S1, S2>, f1: &mut impl FnMut(S1) -> Result<T1, E>, f2: &mut impl FnMut(S2) -> Result<T2, E>) -> Result<Self, E> {
+        Ok(match value {
+            Ok(value) => Ok(f1(value)?),
+            Err(error) => Err(f2(error)?),
+        })
     }
 }
 
+// The hasher is cloned from the input, and the explicit hasher argument is not mapped.
+macro_rules! map_hash {
+    ($($set:ident)::+, $($map:ident)::+) => {
+        impl<T1: Eq + Hash, H: BuildHasher + Clone> Map1<T1> for $($set)::+<T1, H> {
+            type Input<S1> = $($set)::+<S1, H>;
+            #[inline]
+            fn map<S1>(value: Self::Input<S1>, f1: &mut impl FnMut(S1) -> T1) -> Self {
+                let mut output = Self::with_hasher(value.hasher().clone());
+                output.extend(value.into_iter().map(f1));
+                output
+            }
+            #[inline]
+            fn try_map<S1, E>(value: Self::Input<S1>, f1: &mut impl FnMut(S1) -> Result<T1, E>) -> Result<Self, E> {
+                let mut output = Self::with_hasher(value.hasher().clone());
+                for item in value {
+                    output.insert(f1(item)?);
+                }
+                Ok(output)
+            }
+        }
+
+        impl<T1: Eq + Hash, T2, H: BuildHasher + Clone> Map2<T1, T2> for $($map)::+<T1, T2, H> {
+            type Input<S1, S2> = $($map)::+<S1, S2, H>;
+            #[inline]
+            fn map<S1, S2>(value: Self::Input<S1, S2>, f1: &mut impl FnMut(S1) -> T1, f2: &mut impl FnMut(S2) -> T2) -> Self {
+                let mut output = Self::with_hasher(value.hasher().clone());
+                output.extend(value.into_iter().map(|(k, v)| (f1(k), f2(v))));
+                output
+            }
+            #[inline]
+            fn try_map<S1, S2, E>(value: Self::Input<S1, S2>, f1: &mut impl FnMut(S1) -> Result<T1, E>, f2: &mut impl FnMut(S2) -> Result<T2, E>) -> Result<Self, E> {
+                let mut output = Self::with_hasher(value.hasher().clone());
+                for (k, v) in value {
+                    output.insert(f1(k)?, f2(v)?);
+                }
+                Ok(output)
+            }
+        }
+    };
+}
+
+map_hash!(HashSet, HashMap);
+
 impl<T1: Ord, T2> Map2<T1, T2> for BTreeMap<T1, T2> {
     type Input<S1, S2> = BTreeMap<S1, S2>;
     #[inline]
-    fn map<S1, S2>(value: Self::Input<S1, S2>, f1: fn(S1) -> T1, f2: fn(S2) -> T2) -> Self {
+    fn map<S1, S2>(value: Self::Input<S1, S2>, f1: &mut impl FnMut(S1) -> T1, f2: &mut impl FnMut(S2) -> T2) -> Self {
         value.into_iter().map(|(k, v)| (f1(k), f2(v))).collect()
     }
+    #[inline]
+    fn try_map<S1, S2, E>(value: Self::Input<S1, S2>, f1: &mut impl FnMut(S1) -> Result<T1, E>, f2: &mut impl FnMut(S2) -> Result<T2, E>) -> Result<Self, E> {
+        value.into_iter().map(|(k, v)| Ok((f1(k)?, f2(v)?))).collect()
+    }
 }
+
+#[cfg(feature = "arrayvec")]
+impl<T1, const N: usize> Map1<T1> for arrayvec::ArrayVec<T1, N> {
+    type Input<S1> = arrayvec::ArrayVec<S1, N>;
+    #[inline]
+    fn map<S1>(value: Self::Input<S1>, f1: &mut impl FnMut(S1) -> T1) -> Self {
+        value.into_iter().map(f1).collect()
+    }
+    #[inline]
+    fn try_map<S1, E>(value: Self::Input<S1>, f1: &mut impl FnMut(S1) -> Result<T1, E>) -> Result<Self, E> {
+        value.into_iter().map(f1).collect()
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<T1, const N: usize> Map1<T1> for smallvec::SmallVec<[T1; N]> {
+    type Input<S1> = smallvec::SmallVec<[S1; N]>;
+    #[inline]
+    fn map<S1>(value: Self::Input<S1>, f1: &mut impl FnMut(S1) -> T1) -> Self {
+        value.into_iter().map(f1).collect()
+    }
+    #[inline]
+    fn try_map<S1, E>(value: Self::Input<S1>, f1: &mut impl FnMut(S1) -> Result<T1, E>) -> Result<Self, E> {
+        value.into_iter().map(f1).collect()
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+map_hash!(hashbrown::HashSet, hashbrown::HashMap);
+
+#[cfg(feature = "indexmap")]
+map_hash!(indexmap::IndexSet, indexmap::IndexMap);
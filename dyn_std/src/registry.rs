@@ -0,0 +1,276 @@
+//! Registries of named constructors.
+
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Formatter};
+use std::ptr::null_mut;
+use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// Creates the dynamic constructor of an implementation `T`, implemented by `#[dyn_trait]`.
+pub trait Construct<T> {
+    fn construct() -> Box<Self>;
+}
+
+/// Provides the global registry of a dynamic constructor, implemented by `#[dyn_trait(registry)]`.
+pub trait Global: 'static {
+    fn global() -> Result<&'static SyncRegistry<Self>, Error>;
+    fn submissions() -> &'static Submissions<Self>;
+}
+
+/// Gets the value type of a registry alias, such as `<FooRegistry as Collect>::Value`.
+pub trait Collect {
+    type Value: ?Sized;
+}
+
+impl<C: ?Sized> Collect for Registry<C> {
+    type Value = C;
+}
+
+/// A constructor submitted by `#[dyn_impl]` before `main`.
+pub struct Submission<C: ?Sized> {
+    name: &'static str,
+    construct: fn() -> Box<C>,
+    next: AtomicPtr<Submission<C>>,
+}
+
+impl<C: ?Sized> Submission<C> {
+    pub const fn new<T>(name: &'static str) -> Self
+    where
+        C: Construct<T>,
+    {
+        Self {
+            name,
+            construct: C::construct,
+            next: AtomicPtr::new(null_mut()),
+        }
+    }
+}
+
+impl<C: ?Sized + Global> Submission<C> {
+    /// Adds the submission to the global registry, which must not allocate as it runs before `main`.
+    pub fn submit(&'static self) {
+        C::submissions().push(self);
+    }
+}
+
+/// A lock-free list of submitted constructors, which are collected into the global registry on its first access.
+pub struct Submissions<C: ?Sized> {
+    head: AtomicPtr<Submission<C>>,
+}
+
+impl<C: ?Sized> Submissions<C> {
+    pub const fn new() -> Self {
+        Self {
+            head: AtomicPtr::new(null_mut()),
+        }
+    }
+
+    fn push(&self, node: &'static Submission<C>) {
+        let node = node as *const Submission<C> as *mut Submission<C>;
+        let mut head = self.head.load(Ordering::Acquire);
+        loop {
+            // SAFETY: submissions are statics which are only pushed once.
+            unsafe { &*node }.next.store(head, Ordering::Relaxed);
+            match self.head.compare_exchange_weak(head, node, Ordering::AcqRel, Ordering::Acquire) {
+                Ok(_) => return,
+                Err(current) => head = current,
+            }
+        }
+    }
+
+    /// Iterates over the submitted constructors in submission order.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, fn() -> Box<C>)> {
+        let mut nodes = vec![];
+        let mut node = self.head.load(Ordering::Acquire);
+        while !node.is_null() {
+            // SAFETY: nodes are `&'static Submission<C>`.
+            let submission = unsafe { &*node };
+            nodes.push((submission.name, submission.construct));
+            node = submission.next.load(Ordering::Relaxed);
+        }
+        nodes.into_iter().rev()
+    }
+}
+
+impl<C: ?Sized> Default for Submissions<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Errors of registering or aliasing names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The name has already been registered.
+    Duplicate(String),
+    /// The name to be aliased has not been registered.
+    NotFound(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Duplicate(name) => write!(f, "duplicate name `{}` in registry", name),
+            Self::NotFound(name) => write!(f, "name `{}` not found in registry", name),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A registry of named values, such as `Registry<dyn FooConstructor>`.
+///
+/// Values can be found by their names or aliases, and are iterated in registration order.
+pub struct Registry<C: ?Sized> {
+    entries: Vec<(String, Box<C>)>,
+    names: HashMap<String, usize>,
+}
+
+impl<C: ?Sized> Registry<C> {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            names: HashMap::new(),
+        }
+    }
+
+    /// Registers a value under the given name.
+    pub fn insert(&mut self, name: impl Into<String>, value: Box<C>) -> Result<(), Error> {
+        let name = name.into();
+        if self.names.contains_key(&name) {
+            return Err(Error::Duplicate(name))
+        }
+        self.names.insert(name.clone(), self.entries.len());
+        self.entries.push((name, value));
+        Ok(())
+    }
+
+    /// Registers the constructor of `T` under the given name.
+    pub fn register<T>(&mut self, name: impl Into<String>) -> Result<(), Error>
+    where
+        C: Construct<T>,
+    {
+        self.insert(name, C::construct())
+    }
+
+    /// Adds an alias for a registered name.
+    pub fn alias(&mut self, alias: impl Into<String>, name: &str) -> Result<(), Error> {
+        let alias = alias.into();
+        let Some(&index) = self.names.get(name) else {
+            return Err(Error::NotFound(name.into()))
+        };
+        if self.names.contains_key(&alias) {
+            return Err(Error::Duplicate(alias))
+        }
+        self.names.insert(alias, index);
+        Ok(())
+    }
+
+    /// Gets a value by its name or alias.
+    pub fn get(&self, name: &str) -> Option<&C> {
+        self.names.get(name).map(|&index| self.entries[index].1.as_ref())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.contains_key(name)
+    }
+
+    /// Returns the number of registered values, excluding aliases.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterates over the registered names and values in registration order, excluding aliases.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &C)> {
+        self.entries.iter().map(|(name, value)| (name.as_str(), value.as_ref()))
+    }
+}
+
+impl<C: ?Sized + Global> Registry<C> {
+    /// Returns the global registry, or the error of collecting its submissions.
+    pub fn global() -> Result<&'static SyncRegistry<C>, Error> {
+        C::global()
+    }
+}
+
+impl<C: ?Sized> Default for Registry<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: ?Sized> Debug for Registry<C> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_list().entries(self.entries.iter().map(|(name, _)| name)).finish()
+    }
+}
+
+/// A thread-safe [`Registry`], which can be used as a global registry.
+pub struct SyncRegistry<C: ?Sized>(RwLock<Registry<C>>);
+
+impl<C: ?Sized> SyncRegistry<C> {
+    pub fn new() -> Self {
+        Self(RwLock::new(Registry::new()))
+    }
+
+    /// Locks the registry for reading.
+    pub fn read(&self) -> RwLockReadGuard<'_, Registry<C>> {
+        self.0.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Locks the registry for writing.
+    pub fn write(&self) -> RwLockWriteGuard<'_, Registry<C>> {
+        self.0.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Registers a value under the given name.
+    pub fn insert(&self, name: impl Into<String>, value: Box<C>) -> Result<(), Error> {
+        self.write().insert(name, value)
+    }
+
+    /// Registers the constructor of `T` under the given name.
+    pub fn register<T>(&self, name: impl Into<String>) -> Result<(), Error>
+    where
+        C: Construct<T>,
+    {
+        self.write().register::<T>(name)
+    }
+
+    /// Adds an alias for a registered name.
+    pub fn alias(&self, alias: impl Into<String>, name: &str) -> Result<(), Error> {
+        self.write().alias(alias, name)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.read().contains(name)
+    }
+}
+
+impl<C: ?Sized + Global> SyncRegistry<C> {
+    /// Creates a registry of the constructors submitted by `#[dyn_impl]`.
+    ///
+    /// Returns [`Error::Duplicate`] if a name has been submitted more than once.
+    pub fn submitted() -> Result<Self, Error> {
+        let mut registry = Registry::new();
+        for (name, construct) in C::submissions().iter() {
+            registry.insert(name, construct())?;
+        }
+        Ok(Self(RwLock::new(registry)))
+    }
+}
+
+impl<C: ?Sized> Default for SyncRegistry<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: ?Sized> Debug for SyncRegistry<C> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        self.read().fmt(f)
+    }
+}
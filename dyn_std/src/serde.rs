@@ -0,0 +1,327 @@
+//! Serialization of trait objects with type-tagged encoding.
+//!
+//! Values are tagged with their [`DynName`], either externally as `{"name": {..}}`,
+//! or internally as `{"tag": "name", ..}`. Decoding dispatches through a [`Registry`](crate::registry::Registry) of constructors,
+//! whose names must be the same as the tags.
+
+use core::fmt::Formatter;
+use core::marker::PhantomData;
+
+use ::serde::de::{DeserializeOwned, DeserializeSeed, IgnoredAny, MapAccess, Visitor};
+use ::serde::ser::{Impossible, SerializeMap, SerializeStruct};
+
+use crate::adapt::Wrap;
+use crate::{Constructor, DynName, Instance};
+
+pub use ::erased_serde;
+pub use ::serde;
+
+/// Object-safe version of [`serde::Serialize`], which also provides the tag of the value.
+pub trait Serialize {
+    fn dyn_name(&self) -> &'static str;
+    fn as_serialize(&self) -> &dyn erased_serde::Serialize;
+}
+
+impl<T: serde::Serialize + DynName> Serialize for Instance<T> {
+    #[inline]
+    fn dyn_name(&self) -> &'static str {
+        T::NAME
+    }
+
+    #[inline]
+    fn as_serialize(&self) -> &dyn erased_serde::Serialize {
+        &self.0
+    }
+}
+
+impl<T: serde::Serialize> serde::Serialize for Instance<T> {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Object-safe version of [`serde::de::DeserializeOwned`] for constructors, which creates `Box<I>`.
+pub trait Deserialize<I: ?Sized> {
+    fn dyn_deserialize(&self, deserializer: &mut dyn erased_serde::Deserializer<'_>) -> Result<Box<I>, erased_serde::Error>;
+
+    /// Returns a `'static` copy of the constructor, so that a lock of the registry is not held while deserializing,
+    /// as nested values look it up again.
+    fn as_static(&self) -> &'static dyn Deserialize<I>
+    where
+        I: 'static;
+}
+
+impl<T: DeserializeOwned + 'static, I: ?Sized + Wrap<T>> Deserialize<I> for Constructor<T> {
+    #[inline]
+    fn dyn_deserialize(&self, deserializer: &mut dyn erased_serde::Deserializer<'_>) -> Result<Box<I>, erased_serde::Error> {
+        erased_serde::deserialize(deserializer).map(I::wrap)
+    }
+
+    #[inline]
+    fn as_static(&self) -> &'static dyn Deserialize<I>
+    where
+        I: 'static,
+    {
+        &Constructor(PhantomData)
+    }
+}
+
+/// Serializes a value tagged by its name, internally if `tag` is given, and externally otherwise.
+pub fn serialize<T, S>(value: &T, tag: Option<&'static str>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: ?Sized + Serialize,
+    S: serde::Serializer,
+{
+    match tag {
+        None => {
+            let mut map = serializer.serialize_map(Some(1))?;
+            map.serialize_entry(value.dyn_name(), value.as_serialize())?;
+            map.end()
+        },
+        Some(tag) => serde::Serialize::serialize(value.as_serialize(), TaggedSerializer {
+            tag,
+            name: value.dyn_name(),
+            inner: serializer,
+        }),
+    }
+}
+
+/// Deserializes a value tagged by its name through the constructors found by `get`,
+/// such as `|name| registry.get(name)` for a [`Registry`](crate::registry::Registry),
+/// or `|name| Some(registry.read().get(name)?.as_static())` for a [`SyncRegistry`](crate::registry::SyncRegistry).
+///
+/// An internal `tag` must be the first entry of the map, as it is when serialized by [`serialize`].
+pub fn deserialize<'r, 'de, I, C, D>(get: impl Fn(&str) -> Option<&'r C>, tag: Option<&'static str>, deserializer: D) -> Result<Box<I>, D::Error>
+where
+    I: ?Sized,
+    C: ?Sized + Deserialize<I> + 'r,
+    D: serde::Deserializer<'de>,
+{
+    deserializer.deserialize_map(TaggedVisitor {
+        get,
+        tag,
+        marker: PhantomData,
+    })
+}
+
+fn unsupported<E: serde::ser::Error>(kind: &str) -> E {
+    E::custom(format_args!("cannot serialize {} with an internal tag", kind))
+}
+
+/// Serializes structs and maps with an extra tag entry.
+struct TaggedSerializer<S> {
+    tag: &'static str,
+    name: &'static str,
+    inner: S,
+}
+
+impl<S: serde::Serializer> TaggedSerializer<S> {
+    fn tagged_map(self, len: Option<usize>) -> Result<S::SerializeMap, S::Error> {
+        let mut map = self.inner.serialize_map(len.map(|len| len + 1))?;
+        map.serialize_entry(self.tag, self.name)?;
+        Ok(map)
+    }
+}
+
+macro_rules! unsupported {
+    ($($method:ident($($ty:ty),*) => $kind:literal,)*) => {
+        $(fn $method(self, $(_: $ty),*) -> Result<S::Ok, S::Error> {
+            Err(unsupported($kind))
+        })*
+    };
+}
+
+impl<S: serde::Serializer> serde::Serializer for TaggedSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = Impossible<S::Ok, S::Error>;
+    type SerializeTuple = Impossible<S::Ok, S::Error>;
+    type SerializeTupleStruct = Impossible<S::Ok, S::Error>;
+    type SerializeTupleVariant = Impossible<S::Ok, S::Error>;
+    type SerializeMap = S::SerializeMap;
+    type SerializeStruct = TaggedStruct<S::SerializeMap>;
+    type SerializeStructVariant = Impossible<S::Ok, S::Error>;
+
+    unsupported! {
+        serialize_bool(bool) => "a boolean",
+        serialize_i8(i8) => "an integer",
+        serialize_i16(i16) => "an integer",
+        serialize_i32(i32) => "an integer",
+        serialize_i64(i64) => "an integer",
+        serialize_i128(i128) => "an integer",
+        serialize_u8(u8) => "an integer",
+        serialize_u16(u16) => "an integer",
+        serialize_u32(u32) => "an integer",
+        serialize_u64(u64) => "an integer",
+        serialize_u128(u128) => "an integer",
+        serialize_f32(f32) => "a float",
+        serialize_f64(f64) => "a float",
+        serialize_char(char) => "a char",
+        serialize_str(&str) => "a string",
+        serialize_bytes(&[u8]) => "bytes",
+        serialize_none() => "an option",
+        serialize_unit_variant(&'static str, u32, &'static str) => "an enum",
+    }
+
+    fn serialize_some<T: ?Sized + serde::Serialize>(self, _: &T) -> Result<S::Ok, S::Error> {
+        Err(unsupported("an option"))
+    }
+
+    fn serialize_unit(self) -> Result<S::Ok, S::Error> {
+        self.tagged_map(Some(0))?.end()
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<S::Ok, S::Error> {
+        self.tagged_map(Some(0))?.end()
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + serde::Serialize>(self, _: &'static str, value: &T) -> Result<S::Ok, S::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + serde::Serialize>(self, _: &'static str, _: u32, _: &'static str, _: &T) -> Result<S::Ok, S::Error> {
+        Err(unsupported("an enum"))
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, S::Error> {
+        Err(unsupported("a sequence"))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, S::Error> {
+        Err(unsupported("a tuple"))
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeTupleStruct, S::Error> {
+        Err(unsupported("a tuple struct"))
+    }
+
+    fn serialize_tuple_variant(self, _: &'static str, _: u32, _: &'static str, _: usize) -> Result<Self::SerializeTupleVariant, S::Error> {
+        Err(unsupported("an enum"))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<S::SerializeMap, S::Error> {
+        self.tagged_map(len)
+    }
+
+    fn serialize_struct(self, _: &'static str, len: usize) -> Result<Self::SerializeStruct, S::Error> {
+        self.tagged_map(Some(len)).map(TaggedStruct)
+    }
+
+    fn serialize_struct_variant(self, _: &'static str, _: u32, _: &'static str, _: usize) -> Result<Self::SerializeStructVariant, S::Error> {
+        Err(unsupported("an enum"))
+    }
+}
+
+/// Serializes the fields of a struct as entries of a map.
+struct TaggedStruct<M>(M);
+
+impl<M: SerializeMap> SerializeStruct for TaggedStruct<M> {
+    type Ok = M::Ok;
+    type Error = M::Error;
+
+    fn serialize_field<T: ?Sized + serde::Serialize>(&mut self, key: &'static str, value: &T) -> Result<(), M::Error> {
+        self.0.serialize_entry(key, value)
+    }
+
+    fn end(self) -> Result<M::Ok, M::Error> {
+        self.0.end()
+    }
+}
+
+struct TaggedVisitor<I: ?Sized, F> {
+    get: F,
+    tag: Option<&'static str>,
+    marker: PhantomData<fn() -> Box<I>>,
+}
+
+impl<'r, I: ?Sized, C: ?Sized + 'r, F: Fn(&str) -> Option<&'r C>> TaggedVisitor<I, F> {
+    fn get<E: serde::de::Error>(&self, name: &str) -> Result<&'r C, E> {
+        (self.get)(name).ok_or_else(|| E::custom(format_args!("unknown type name `{}`", name)))
+    }
+}
+
+impl<'r, 'de, I, C, F> Visitor<'de> for TaggedVisitor<I, F>
+where
+    I: ?Sized,
+    C: ?Sized + Deserialize<I> + 'r,
+    F: Fn(&str) -> Option<&'r C>,
+{
+    type Value = Box<I>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        match self.tag {
+            None => formatter.write_str("a map with a single entry keyed by a type name"),
+            Some(tag) => write!(formatter, "a map starting with the tag `{}`", tag),
+        }
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Box<I>, A::Error> {
+        use serde::de::Error;
+        let Some(key) = map.next_key::<String>()? else {
+            return Err(A::Error::invalid_length(0, &self))
+        };
+        let Some(tag) = self.tag else {
+            let value = map.next_value_seed(Seed {
+                constructor: self.get(&key)?,
+                marker: PhantomData,
+            })?;
+            if map.next_key::<IgnoredAny>()?.is_some() {
+                return Err(A::Error::invalid_length(2, &self))
+            }
+            return Ok(value)
+        };
+        if key != tag {
+            return Err(A::Error::missing_field(tag))
+        }
+        let name = map.next_value::<String>()?;
+        let constructor = self.get(&name)?;
+        let mut deserializer = <dyn erased_serde::Deserializer>::erase(Untagged(map));
+        constructor.dyn_deserialize(&mut deserializer).map_err(A::Error::custom)
+    }
+}
+
+/// Deserializes the entries after an internal tag, which are empty for unit structs.
+struct Untagged<A>(A);
+
+impl<'de, A: MapAccess<'de>> serde::Deserializer<'de> for Untagged<A> {
+    type Error = A::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, A::Error> {
+        visitor.visit_map(self.0)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value, A::Error> {
+        use serde::de::Error;
+        match self.0.next_key::<IgnoredAny>()? {
+            None => visitor.visit_unit(),
+            Some(_) => Err(A::Error::invalid_type(serde::de::Unexpected::Map, &visitor)),
+        }
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(self, _: &'static str, visitor: V) -> Result<V::Value, A::Error> {
+        self.deserialize_unit(visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option newtype_struct seq tuple tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Deserializes a value through its constructor.
+struct Seed<'r, I: ?Sized, C: ?Sized> {
+    constructor: &'r C,
+    marker: PhantomData<fn() -> Box<I>>,
+}
+
+impl<'de, I: ?Sized, C: ?Sized + Deserialize<I>> DeserializeSeed<'de> for Seed<'_, I, C> {
+    type Value = Box<I>;
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<Box<I>, D::Error> {
+        use serde::de::Error;
+        let mut deserializer = <dyn erased_serde::Deserializer>::erase(deserializer);
+        self.constructor.dyn_deserialize(&mut deserializer).map_err(D::Error::custom)
+    }
+}
+